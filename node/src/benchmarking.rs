@@ -29,7 +29,7 @@ use sc_client_api::BlockBackend;
 use sp_core::{Encode, Pair};
 use sp_inherents::{InherentData, InherentDataProvider};
 use sp_keyring::Sr25519Keyring;
-use sp_runtime::{OpaqueExtrinsic, SaturatedConversion};
+use sp_runtime::{generic::Era, OpaqueExtrinsic, SaturatedConversion};
 
 use std::{sync::Arc, time::Duration};
 
@@ -127,18 +127,35 @@ pub fn create_benchmark_extrinsic(
 		.checked_next_power_of_two()
 		.map(|c| c / 2)
 		.unwrap_or(2) as u64;
+	create_signed_extrinsic(
+		sender,
+		call,
+		nonce,
+		genesis_hash,
+		Era::mortal(period, best_block.saturated_into()),
+		best_hash,
+	)
+}
+
+/// Create a transaction of `call` signed by `sender`, valid during `era` starting at the
+/// `checkpoint` block. Needs no client, so it is also used to sign extrinsics offline.
+pub fn create_signed_extrinsic(
+	sender: sp_core::sr25519::Pair,
+	call: runtime::RuntimeCall,
+	nonce: u32,
+	genesis_hash: runtime::Hash,
+	era: Era,
+	checkpoint: runtime::Hash,
+) -> runtime::UncheckedExtrinsic {
 	let extra: runtime::SignedExtra = (
 		frame_system::CheckNonZeroSender::<runtime::Runtime>::new(),
 		frame_system::CheckSpecVersion::<runtime::Runtime>::new(),
 		frame_system::CheckTxVersion::<runtime::Runtime>::new(),
 		frame_system::CheckGenesis::<runtime::Runtime>::new(),
-		frame_system::CheckEra::<runtime::Runtime>::from(sp_runtime::generic::Era::mortal(
-			period,
-			best_block.saturated_into(),
-		)),
+		frame_system::CheckEra::<runtime::Runtime>::from(era),
 		frame_system::CheckNonce::<runtime::Runtime>::from(nonce),
 		frame_system::CheckWeight::<runtime::Runtime>::new(),
-		pallet_transaction_payment::ChargeTransactionPayment::<runtime::Runtime>::from(0),
+		runtime::ChargeTransactionPaymentOrFeelessTrace::from(0),
 	);
 
 	let raw_payload = runtime::SignedPayload::from_raw(
@@ -149,7 +166,7 @@ pub fn create_benchmark_extrinsic(
 			runtime::VERSION.spec_version,
 			runtime::VERSION.transaction_version,
 			genesis_hash,
-			best_hash,
+			checkpoint,
 			(),
 			(),
 			(),
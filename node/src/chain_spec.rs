@@ -1,6 +1,6 @@
 use node_template_runtime::{
-	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, Signature, SudoConfig,
-	SystemConfig, WASM_BINARY,
+	pallet_verifiable::GenesisDID, AccountId, AuraConfig, BalancesConfig, GenesisConfig,
+	GrandpaConfig, Signature, SudoConfig, SystemConfig, VerifiableConfig, WASM_BINARY,
 };
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -59,6 +59,11 @@ pub fn development_config() -> Result<ChainSpec, String> {
 					get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
 				],
+				// Example DID controllers
+				vec![
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					get_account_id_from_seed::<sr25519::Public>("Bob"),
+				],
 				true,
 			)
 		},
@@ -107,6 +112,11 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 					get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
 				],
+				// Example DID controllers
+				vec![
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					get_account_id_from_seed::<sr25519::Public>("Bob"),
+				],
 				true,
 			)
 		},
@@ -130,6 +140,7 @@ fn testnet_genesis(
 	initial_authorities: Vec<(AuraId, GrandpaId)>,
 	root_key: AccountId,
 	endowed_accounts: Vec<AccountId>,
+	did_controllers: Vec<AccountId>,
 	_enable_println: bool,
 ) -> GenesisConfig {
 	GenesisConfig {
@@ -152,5 +163,23 @@ fn testnet_genesis(
 			key: Some(root_key),
 		},
 		transaction_payment: Default::default(),
+		verifiable: VerifiableConfig {
+			// Example DIDs to resolve right after start
+			dids: did_controllers
+				.into_iter()
+				.map(|controller| {
+					let did_uri = format!("did:alto:{controller}");
+					let did_document_metadata = format!(r#"{{"controller": "{did_uri}"}}"#);
+					GenesisDID {
+						did_uri: did_uri.into_bytes(),
+						controller,
+						did_document_metadata: did_document_metadata.into_bytes(),
+					}
+				})
+				.collect(),
+			credentials: vec![],
+			custom_statuses: vec![],
+			roles: vec![],
+		},
 	}
 }
@@ -50,4 +50,8 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
+
+	/// Offline DID and verifiable credential operations.
+	#[clap(subcommand)]
+	Verifiable(crate::verifiable::VerifiableCmd),
 }
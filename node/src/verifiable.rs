@@ -0,0 +1,333 @@
+//! Offline DID and verifiable credential operations of the `verifiable` subcommand.
+//!
+//! Extrinsics are signed with an immortal era against the given genesis hash and printed as
+//! hex, ready to be submitted through `author_submitExtrinsic` from another machine.
+
+use crate::benchmarking::create_signed_extrinsic;
+
+use node_template_runtime::{
+	self as runtime,
+	pallet_verifiable::{
+		self, DIDMetadataPayload, FingerprintHasher, VerifiableCredentialMetadataPayload,
+		VerifiableCredentialStatus,
+	},
+	AccountId, BlockNumber, Hash, VerifiableCall,
+};
+use sc_cli::Result;
+use sp_core::{bytes, sr25519, Bytes, Encode, Get, Pair};
+use sp_runtime::{generic::Era, BoundedVec};
+
+use std::path::{Path, PathBuf};
+
+/// Fingerprint hasher configured in the runtime.
+type RuntimeFingerprintHasher = <runtime::Runtime as pallet_verifiable::Config>::FingerprintHasher;
+
+/// Offline DID and verifiable credential operations.
+#[derive(Debug, clap::Subcommand)]
+pub enum VerifiableCmd {
+	/// Generate an sr25519 controller key and the DID it controls.
+	GenerateDid,
+
+	/// Build and sign a `create_did` extrinsic.
+	CreateDid(CreateDidCmd),
+
+	/// Build and sign a `create_verifiable_credential` extrinsic.
+	CreateCredential(CreateCredentialCmd),
+
+	/// Build and sign a `trace_credential` extrinsic.
+	TraceCredential(TraceCredentialCmd),
+
+	/// Compute the fingerprint of a verifiable credential JSON file.
+	Fingerprint(FingerprintCmd),
+}
+
+/// Signer of an offline extrinsic.
+#[derive(Debug, clap::Args)]
+pub struct SignerParams {
+	/// Secret URI of the sr25519 signing key, e.g. a secret phrase or `//Alice`.
+	#[clap(long)]
+	pub suri: String,
+
+	/// Nonce of the signing account.
+	#[clap(long, default_value = "0")]
+	pub nonce: u32,
+
+	/// Genesis hash of the chain the extrinsic is submitted to.
+	#[clap(long, value_parser)]
+	pub genesis_hash: Hash,
+}
+
+/// Verifiable credential an extrinsic refers to.
+#[derive(Debug, clap::Args)]
+#[clap(group(clap::ArgGroup::new("credential").required(true).args(&["fingerprint", "file"])))]
+pub struct CredentialParams {
+	/// Fingerprint of the verifiable credential as hex.
+	#[clap(long, value_parser)]
+	pub fingerprint: Option<Bytes>,
+
+	/// Verifiable credential JSON file to compute the fingerprint of.
+	#[clap(long, value_parser)]
+	pub file: Option<PathBuf>,
+}
+
+/// The `verifiable create-did` command.
+#[derive(Debug, clap::Parser)]
+pub struct CreateDidCmd {
+	/// DID URI, defaults to `did:alto:<signer account>`.
+	#[clap(long)]
+	pub did_uri: Option<String>,
+
+	/// DID Document metadata.
+	#[clap(long)]
+	pub document_metadata: Option<String>,
+
+	/// DID resolution metadata.
+	#[clap(long)]
+	pub resolution_metadata: Option<String>,
+
+	/// IPFS URI of the DID Document.
+	#[clap(long)]
+	pub did_ref: Option<String>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub signer: SignerParams,
+}
+
+/// The `verifiable create-credential` command.
+#[derive(Debug, clap::Parser)]
+pub struct CreateCredentialCmd {
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub credential: CredentialParams,
+
+	/// Public key of the holder as hex.
+	#[clap(long, value_parser)]
+	pub public_key: Bytes,
+
+	/// Controller of the verifiable credential.
+	#[clap(long, value_parser)]
+	pub controller: Option<AccountId>,
+
+	/// Block number from which the verifiable credential is expired.
+	#[clap(long)]
+	pub expiry: Option<BlockNumber>,
+
+	/// DID URI of the issuer.
+	#[clap(long)]
+	pub issuer_did: Option<String>,
+
+	/// DID URI of the subject.
+	#[clap(long)]
+	pub subject_did: Option<String>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub signer: SignerParams,
+}
+
+/// The `verifiable trace-credential` command.
+#[derive(Debug, clap::Parser)]
+pub struct TraceCredentialCmd {
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub credential: CredentialParams,
+
+	/// Status to log, e.g. `scanned` or `custom:3` for a custom status id.
+	#[clap(long, value_parser = parse_status)]
+	pub status: VerifiableCredentialStatus,
+
+	/// Account the status is logged for.
+	#[clap(long, value_parser)]
+	pub account: Option<AccountId>,
+
+	/// Location code, sensor reading or other metadata attached to the trail entry.
+	#[clap(long)]
+	pub metadata: Option<String>,
+
+	/// Hash of the evidence backing the trail entry.
+	#[clap(long, value_parser)]
+	pub evidence_hash: Option<Hash>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub signer: SignerParams,
+}
+
+/// The `verifiable fingerprint` command.
+#[derive(Debug, clap::Parser)]
+pub struct FingerprintCmd {
+	/// Verifiable credential JSON file.
+	#[clap(value_parser)]
+	pub file: PathBuf,
+}
+
+impl VerifiableCmd {
+	/// Run the command.
+	pub fn run(&self) -> Result<()> {
+		match self {
+			VerifiableCmd::GenerateDid => {
+				let (pair, phrase, _) = sr25519::Pair::generate_with_phrase(None);
+				let account_id = AccountId::from(pair.public());
+				println!("Secret phrase: {}", phrase);
+				println!("Public key:    {}", bytes::to_hex(pair.public().as_ref(), false));
+				println!("Account ID:    {}", account_id);
+				println!("DID:           did:alto:{}", account_id);
+				Ok(())
+			},
+			VerifiableCmd::CreateDid(cmd) => cmd.run(),
+			VerifiableCmd::CreateCredential(cmd) => cmd.run(),
+			VerifiableCmd::TraceCredential(cmd) => cmd.run(),
+			VerifiableCmd::Fingerprint(cmd) => {
+				println!("{}", bytes::to_hex(&fingerprint_file(&cmd.file)?, false));
+				Ok(())
+			},
+		}
+	}
+}
+
+impl CreateDidCmd {
+	fn run(&self) -> Result<()> {
+		let sender_account_id = AccountId::from(self.signer.pair()?.public());
+		let did_uri = self
+			.did_uri
+			.clone()
+			.unwrap_or_else(|| format!("did:alto:{}", sender_account_id));
+		let call = VerifiableCall::create_did {
+			did_uri: bounded(did_uri.into_bytes(), "DID URI")?,
+			did_input: DIDMetadataPayload {
+				signatures: Default::default(),
+				did_resolution_metadata: bounded_text(
+					&self.resolution_metadata,
+					"DID resolution metadata",
+				)?,
+				did_document_metadata: bounded_text(
+					&self.document_metadata,
+					"DID Document metadata",
+				)?,
+				did_ref: bounded_text(&self.did_ref, "DID reference")?,
+				sender_account_id,
+			},
+		};
+		self.signer.sign(call.into())
+	}
+}
+
+impl CreateCredentialCmd {
+	fn run(&self) -> Result<()> {
+		let call = VerifiableCall::create_verifiable_credential {
+			vc_fingerprint: self.credential.fingerprint()?,
+			verifiable_credential_input_metadata: VerifiableCredentialMetadataPayload {
+				account_id: self.controller.clone(),
+				public_key: bounded(self.public_key.to_vec(), "Public key")?,
+				active: Some(true),
+				expiry_block_number: self.expiry,
+				issuer_did: bounded_text(&self.issuer_did, "Issuer DID URI")?,
+				subject_did: bounded_text(&self.subject_did, "Subject DID URI")?,
+			},
+		};
+		self.signer.sign(call.into())
+	}
+}
+
+impl TraceCredentialCmd {
+	fn run(&self) -> Result<()> {
+		let call = VerifiableCall::trace_credential {
+			account_id: self.account.clone(),
+			vc_fingerprint: self.credential.fingerprint()?,
+			status: self.status.clone(),
+			metadata: bounded_text(&self.metadata, "Trail metadata")?,
+			evidence_hash: self.evidence_hash,
+		};
+		self.signer.sign(call.into())
+	}
+}
+
+impl SignerParams {
+	fn pair(&self) -> Result<sr25519::Pair> {
+		sr25519::Pair::from_string(&self.suri, None)
+			.map_err(|e| format!("Invalid secret URI: {:?}", e).into())
+	}
+
+	/// Sign `call` and print the extrinsic as hex.
+	fn sign(&self, call: runtime::RuntimeCall) -> Result<()> {
+		let extrinsic = create_signed_extrinsic(
+			self.pair()?,
+			call,
+			self.nonce,
+			self.genesis_hash,
+			Era::Immortal,
+			self.genesis_hash,
+		);
+		println!("{}", bytes::to_hex(&extrinsic.encode(), false));
+		Ok(())
+	}
+}
+
+impl CredentialParams {
+	fn fingerprint(&self) -> Result<BoundedVec<u8, runtime::VCFingerPrintSize>> {
+		let fingerprint = match (&self.fingerprint, &self.file) {
+			(Some(fingerprint), _) => fingerprint.to_vec(),
+			(None, Some(file)) => fingerprint_file(file)?,
+			(None, None) => return Err("Either --fingerprint or --file is required".into()),
+		};
+		bounded(fingerprint, "Fingerprint")
+	}
+}
+
+/// Fingerprint of a verifiable credential JSON file, computed with the runtime fingerprint
+/// hasher over the JSON with sorted object keys and no whitespace, so formatting does not change
+/// the fingerprint.
+fn fingerprint_file(file: &Path) -> Result<Vec<u8>> {
+	let credential: serde_json::Value = serde_json::from_slice(&std::fs::read(file)?)
+		.map_err(|e| format!("Invalid JSON in {}: {}", file.display(), e))?;
+	let content = serde_json::to_vec(&canonical_json(credential))
+		.map_err(|e| format!("Unable to encode {}: {}", file.display(), e))?;
+	Ok(RuntimeFingerprintHasher::fingerprint(&content))
+}
+
+fn canonical_json(value: serde_json::Value) -> serde_json::Value {
+	match value {
+		serde_json::Value::Object(map) => {
+			let mut entries: Vec<_> =
+				map.into_iter().map(|(key, value)| (key, canonical_json(value))).collect();
+			entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+			serde_json::Value::Object(entries.into_iter().collect())
+		},
+		serde_json::Value::Array(values) =>
+			serde_json::Value::Array(values.into_iter().map(canonical_json).collect()),
+		value => value,
+	}
+}
+
+fn parse_status(status: &str) -> std::result::Result<VerifiableCredentialStatus, String> {
+	Ok(match status.to_lowercase().as_str() {
+		"pending" => VerifiableCredentialStatus::Pending,
+		"created" => VerifiableCredentialStatus::Created,
+		"scanned" => VerifiableCredentialStatus::Scanned,
+		"suspended" => VerifiableCredentialStatus::Suspended,
+		"resumed" => VerifiableCredentialStatus::Resumed,
+		"migrated" | "split" | "merged" =>
+			return Err(format!("`{}` is only set by migrating, splitting or merging", status)),
+		"frozen" => VerifiableCredentialStatus::Frozen,
+		other => match other.strip_prefix("custom:") {
+			Some(status_id) => VerifiableCredentialStatus::Custom(
+				status_id.parse().map_err(|e| format!("Invalid custom status id: {}", e))?,
+			),
+			None => return Err(format!("Unknown status `{}`", status)),
+		},
+	})
+}
+
+fn bounded<S: Get<u32>>(bytes: Vec<u8>, name: &str) -> Result<BoundedVec<u8, S>> {
+	bytes
+		.try_into()
+		.map_err(|_| format!("{} exceeds {} bytes", name, S::get()).into())
+}
+
+fn bounded_text<S: Get<u32>>(
+	text: &Option<String>,
+	name: &str,
+) -> Result<Option<BoundedVec<u8, S>>> {
+	text.clone().map(|text| bounded(text.into_bytes(), name)).transpose()
+}
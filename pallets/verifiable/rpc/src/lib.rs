@@ -0,0 +1,722 @@
+//! JSON-RPC interface of the verifiable pallet.
+//!
+//! Every method reads state through the `VerifiableApi` runtime API at the `at` block hash,
+//! defaulting to the best block. DID URIs are passed and returned as strings, fingerprints and
+//! public keys as hex encoded bytes, and metadata as strings when it is valid UTF-8.
+//!
+//! Subscriptions push the current state of a DID Document or verifiable credential, then a new
+//! update at every best or finalized block in which that state changed. Blocks are selected from
+//! storage change notifications of the subscribed keys, so unrelated blocks are never read.
+
+#![warn(missing_docs)]
+
+use std::{collections::HashMap, fmt::Debug, marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use futures::{future, stream, FutureExt, Stream, StreamExt};
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::{
+		error::{CallError, ErrorObject},
+		SubscriptionResult,
+	},
+	SubscriptionSink,
+};
+use pallet_verifiable::{
+	CredentialStatusReport, CredentialValidity, DIDResolution, TrailFilter, TrailPage,
+	TrailSummary, VerifiableCredentialResolution, VerifiableCredentialStatus,
+};
+pub use pallet_verifiable_runtime_api::VerifiableApi as VerifiableRuntimeApi;
+use sc_client_api::{BlockchainEvents, FinalityNotifications};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{storage::StorageKey, traits::SpawnNamed, Bytes, Get};
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+#[cfg(test)]
+mod tests;
+
+/// Upper bound of the items returned by a single page.
+pub const MAX_PAGE_LIMIT: u32 = 1000;
+
+/// Error codes of the verifiable RPC methods.
+pub enum Error {
+	/// The runtime API call failed.
+	RuntimeError,
+	/// A page limit is zero or above `MAX_PAGE_LIMIT`.
+	InvalidLimit,
+	/// A subscribed DID URI or fingerprint exceeds its size bound.
+	InvalidKey,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::InvalidLimit => 2,
+			Error::InvalidKey => 3,
+		}
+	}
+}
+
+/// Metadata rendered as a string when it is valid UTF-8 and as hex encoded bytes otherwise.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Metadata {
+	/// UTF-8 metadata.
+	Text(String),
+	/// Binary metadata.
+	Bytes(Bytes),
+}
+
+impl From<Vec<u8>> for Metadata {
+	fn from(metadata: Vec<u8>) -> Self {
+		String::from_utf8(metadata)
+			.map(Metadata::Text)
+			.unwrap_or_else(|e| Metadata::Bytes(e.into_bytes().into()))
+	}
+}
+
+/// DID Document returned by `verifiable_getDid`.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Did<AccountId, BlockNumber> {
+	/// DID URI.
+	pub did_uri: String,
+	/// DID resolution metadata.
+	pub did_resolution_metadata: Option<Metadata>,
+	/// DID Document metadata.
+	pub did_document_metadata: Option<Metadata>,
+	/// IPFS URI of the DID Document.
+	pub did_ref: Option<Metadata>,
+	/// Account that sent the DID Document.
+	pub sender_account_id: AccountId,
+	/// Block number the DID Document was created at.
+	pub block_number: BlockNumber,
+	/// Block number the DID Document was last updated at.
+	pub updated_block_number: BlockNumber,
+	/// ISO 8601 created time.
+	pub created: String,
+	/// ISO 8601 updated time.
+	pub updated: String,
+}
+
+impl<AccountId, BlockNumber> From<DIDResolution<AccountId, BlockNumber>>
+	for Did<AccountId, BlockNumber>
+{
+	fn from(did: DIDResolution<AccountId, BlockNumber>) -> Self {
+		Self {
+			did_uri: utf8(did.did_uri),
+			did_resolution_metadata: did.did_resolution_metadata.map(Into::into),
+			did_document_metadata: did.did_document_metadata.map(Into::into),
+			did_ref: did.did_ref.map(Into::into),
+			sender_account_id: did.sender_account_id,
+			block_number: did.block_number,
+			updated_block_number: did.updated_block_number,
+			created: utf8(did.created),
+			updated: utf8(did.updated),
+		}
+	}
+}
+
+/// Verifiable credential returned by `verifiable_getCredential`.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Credential<AccountId, BlockNumber> {
+	/// Verifiable credential fingerprint.
+	pub vc_fingerprint: Bytes,
+	/// Controller of the verifiable credential.
+	pub account_id: Option<AccountId>,
+	/// Public key of the holder.
+	pub public_key: Bytes,
+	/// Issuer of the verifiable credential.
+	pub issuer: AccountId,
+	/// DID URI of the issuer.
+	pub issuer_did: Option<String>,
+	/// DID URI of the subject.
+	pub subject_did: Option<String>,
+	/// Whether the verifiable credential is active.
+	pub active: Option<bool>,
+	/// Block number the verifiable credential was created at.
+	pub block_number: BlockNumber,
+	/// Block number the verifiable credential was last updated at.
+	pub updated_block_number: BlockNumber,
+	/// Block number from which the verifiable credential is expired.
+	pub expiry_block_number: Option<BlockNumber>,
+	/// ISO 8601 created time.
+	pub created: String,
+	/// ISO 8601 updated time.
+	pub updated: String,
+}
+
+impl<AccountId, BlockNumber> From<VerifiableCredentialResolution<AccountId, BlockNumber>>
+	for Credential<AccountId, BlockNumber>
+{
+	fn from(vc: VerifiableCredentialResolution<AccountId, BlockNumber>) -> Self {
+		Self {
+			vc_fingerprint: vc.vc_fingerprint.into(),
+			account_id: vc.account_id,
+			public_key: vc.public_key.into(),
+			issuer: vc.issuer,
+			issuer_did: vc.issuer_did.map(utf8),
+			subject_did: vc.subject_did.map(utf8),
+			active: vc.active,
+			block_number: vc.block_number,
+			updated_block_number: vc.updated_block_number,
+			expiry_block_number: vc.expiry_block_number,
+			created: utf8(vc.created),
+			updated: utf8(vc.updated),
+		}
+	}
+}
+
+/// Verifiable credential status returned by `verifiable_credentialStatus`.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialStatus<AccountId, BlockNumber> {
+	/// Validity of the verifiable credential.
+	pub validity: CredentialValidity,
+	/// Status of the latest trail entry.
+	pub current_status: Option<VerifiableCredentialStatus>,
+	/// Issuer of the verifiable credential, unknown once it is deleted.
+	pub issuer: Option<AccountId>,
+	/// Block number from which the verifiable credential is expired.
+	pub expiry_block_number: Option<BlockNumber>,
+	/// Block number the verifiable credential was revoked at.
+	pub revoked_block_number: Option<BlockNumber>,
+	/// Block number the verifiable credential was deleted at.
+	pub deleted_block_number: Option<BlockNumber>,
+}
+
+impl<AccountId, BlockNumber> From<CredentialStatusReport<AccountId, BlockNumber>>
+	for CredentialStatus<AccountId, BlockNumber>
+{
+	fn from(report: CredentialStatusReport<AccountId, BlockNumber>) -> Self {
+		Self {
+			validity: report.validity,
+			current_status: report.current_status,
+			issuer: report.issuer,
+			expiry_block_number: report.expiry_block_number,
+			revoked_block_number: report.revoked_block_number,
+			deleted_block_number: report.deleted_block_number,
+		}
+	}
+}
+
+/// Verifiable credential trail entry.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrailEntry<AccountId, BlockNumber, Hash> {
+	/// Sequence number of the entry in the trail.
+	pub sequence: u32,
+	/// Account the entry was logged for.
+	pub account_id: Option<AccountId>,
+	/// Status of the entry.
+	pub status: VerifiableCredentialStatus,
+	/// Block number the entry was logged at.
+	pub block_number: Option<BlockNumber>,
+	/// Time the entry was logged at, in milliseconds since the Unix epoch.
+	pub timestamp: Option<u64>,
+	/// Metadata attached by the tracer.
+	pub metadata: Option<Metadata>,
+	/// Hash of the evidence backing the entry.
+	pub evidence_hash: Option<Hash>,
+	/// Issuer role id held by the tracer.
+	pub role: Option<u16>,
+}
+
+/// Page of a verifiable credential trail returned by `verifiable_getTrail` and
+/// `verifiable_queryTrail`.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrailQueryResult<AccountId, BlockNumber, Hash> {
+	/// Entries of the page.
+	pub entries: Vec<TrailEntry<AccountId, BlockNumber, Hash>>,
+	/// Cursor to pass to resume the query, `None` once the trail is exhausted.
+	pub next_cursor: Option<u32>,
+	/// Current status summary of the verifiable credential.
+	pub summary: TrailSummary<BlockNumber>,
+}
+
+impl<AccountId, BlockNumber, Hash, MetadataSize>
+	From<TrailPage<AccountId, BlockNumber, Hash, MetadataSize>>
+	for TrailQueryResult<AccountId, BlockNumber, Hash>
+where
+	AccountId: Clone + PartialEq + Debug,
+	BlockNumber: Clone + PartialEq + Debug + PartialOrd,
+	Hash: Clone + PartialEq + Debug,
+	MetadataSize: Get<u32>,
+{
+	fn from(page: TrailPage<AccountId, BlockNumber, Hash, MetadataSize>) -> Self {
+		Self {
+			entries: page
+				.entries
+				.into_iter()
+				.map(|(sequence, vc_log)| TrailEntry {
+					sequence,
+					account_id: vc_log.account_id,
+					status: vc_log.status,
+					block_number: vc_log.block_number,
+					timestamp: vc_log.timestamp,
+					metadata: vc_log.metadata.map(|metadata| metadata.into_inner().into()),
+					evidence_hash: vc_log.evidence_hash,
+					role: vc_log.role,
+				})
+				.collect(),
+			next_cursor: page.next_cursor,
+			summary: page.summary,
+		}
+	}
+}
+
+/// Blocks followed by a subscription.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BlockSelection {
+	/// Imported best blocks.
+	Best,
+	/// Finalized blocks.
+	Finalized,
+}
+
+/// State of a verifiable credential pushed by `verifiable_subscribeCredential`.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialState<AccountId, BlockNumber> {
+	/// Verifiable credential, `None` once it is revoked or deleted.
+	pub credential: Option<Credential<AccountId, BlockNumber>>,
+	/// Validity and current trail status, `None` until the verifiable credential is anchored.
+	pub status: Option<CredentialStatus<AccountId, BlockNumber>>,
+}
+
+/// Update pushed by the verifiable subscriptions.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Update<Hash, State> {
+	/// Block the state was read at.
+	pub block: Hash,
+	/// State of the DID Document or verifiable credential at the block.
+	pub state: State,
+}
+
+/// Verifiable pallet RPC methods.
+#[rpc(client, server)]
+pub trait VerifiableApi<BlockHash, Hash, AccountId, BlockNumber> {
+	/// Resolve a DID Document. Returns `null` if the DID does not exist.
+	#[method(name = "verifiable_getDid")]
+	fn get_did(
+		&self,
+		did_uri: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Did<AccountId, BlockNumber>>>;
+
+	/// Resolve a verifiable credential. Returns `null` if the verifiable credential does not
+	/// exist.
+	#[method(name = "verifiable_getCredential")]
+	fn get_credential(
+		&self,
+		vc_fingerprint: Bytes,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Credential<AccountId, BlockNumber>>>;
+
+	/// Page through the trail of a verifiable credential. Pass the `nextCursor` of a page to
+	/// fetch the next one. Returns `null` if the verifiable credential has neither metadata nor
+	/// a trail.
+	#[method(name = "verifiable_getTrail")]
+	fn get_trail(
+		&self,
+		vc_fingerprint: Bytes,
+		cursor: Option<u32>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<TrailQueryResult<AccountId, BlockNumber, Hash>>>;
+
+	/// Query the trail of a verifiable credential, filtered by status, account and block range.
+	/// Pass the `nextCursor` of a page to fetch the next one.
+	#[method(name = "verifiable_queryTrail")]
+	fn query_trail(
+		&self,
+		vc_fingerprint: Bytes,
+		filter: TrailFilter<AccountId, BlockNumber>,
+		cursor: Option<u32>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<TrailQueryResult<AccountId, BlockNumber, Hash>>>;
+
+	/// Validity and current trail status of a verifiable credential, including revoked and
+	/// deleted ones. Returns `null` if the verifiable credential was never anchored.
+	#[method(name = "verifiable_credentialStatus")]
+	fn credential_status(
+		&self,
+		vc_fingerprint: Bytes,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<CredentialStatus<AccountId, BlockNumber>>>;
+
+	/// Page through the DID URIs last created or updated by an account, starting after the
+	/// `startAfter` DID URI.
+	#[method(name = "verifiable_listDidsByAccount")]
+	fn list_dids_by_account(
+		&self,
+		account_id: AccountId,
+		start_after: Option<String>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<String>>;
+
+	/// Subscribe to the changes of a DID Document in best or finalized blocks, defaulting to
+	/// best blocks. The state is `null` while the DID does not exist.
+	#[subscription(
+		name = "verifiable_subscribeDid" => "verifiable_did",
+		unsubscribe = "verifiable_unsubscribeDid",
+		item = Update<BlockHash, Option<Did<AccountId, BlockNumber>>>,
+	)]
+	fn subscribe_did(&self, did_uri: String, blocks: Option<BlockSelection>);
+
+	/// Subscribe to the changes of a verifiable credential, such as updates, trail status changes,
+	/// revocation and deletion, in best or finalized blocks, defaulting to best blocks.
+	#[subscription(
+		name = "verifiable_subscribeCredential" => "verifiable_credential",
+		unsubscribe = "verifiable_unsubscribeCredential",
+		item = Update<BlockHash, CredentialState<AccountId, BlockNumber>>,
+	)]
+	fn subscribe_credential(&self, vc_fingerprint: Bytes, blocks: Option<BlockSelection>);
+}
+
+/// Verifiable pallet RPC handler.
+pub struct Verifiable<C, Block, TrailMetadataSize> {
+	client: Arc<C>,
+	executor: Arc<dyn SpawnNamed>,
+	_marker: PhantomData<(Block, TrailMetadataSize)>,
+}
+
+impl<C, Block, TrailMetadataSize> Verifiable<C, Block, TrailMetadataSize> {
+	/// Create a new instance of the verifiable pallet RPC handler, spawning subscriptions on
+	/// `executor`.
+	pub fn new(client: Arc<C>, executor: Arc<dyn SpawnNamed>) -> Self {
+		Self { client, executor, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, Hash, AccountId, BlockNumber, TrailMetadataSize>
+	VerifiableApiServer<<Block as BlockT>::Hash, Hash, AccountId, BlockNumber>
+	for Verifiable<C, Block, TrailMetadataSize>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockchainEvents<Block>,
+	C: Send + Sync + 'static,
+	C::Api: VerifiableRuntimeApi<Block, Hash, AccountId, BlockNumber, TrailMetadataSize>,
+	Hash: Codec + Clone + PartialEq + Debug + Send + Sync + 'static,
+	AccountId: Codec + Clone + PartialEq + Debug + Send + Sync + 'static,
+	BlockNumber: Codec + Clone + PartialEq + Debug + PartialOrd + Send + Sync + 'static,
+	TrailMetadataSize: Get<u32> + Send + Sync + 'static,
+	Hash: Serialize + for<'de> Deserialize<'de>,
+	AccountId: Serialize + for<'de> Deserialize<'de>,
+	BlockNumber: Serialize + for<'de> Deserialize<'de>,
+{
+	fn get_did(
+		&self,
+		did_uri: String,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Did<AccountId, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = self.block_id(at);
+
+		let did = api
+			.resolve_did(&at, did_uri.into_bytes())
+			.map_err(|e| runtime_error("Unable to resolve DID Document.", e))?;
+		Ok(did.map(Into::into))
+	}
+
+	fn get_credential(
+		&self,
+		vc_fingerprint: Bytes,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Credential<AccountId, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = self.block_id(at);
+
+		let vc = api
+			.resolve_verifiable_credential(&at, vc_fingerprint.to_vec())
+			.map_err(|e| runtime_error("Unable to resolve verifiable credential.", e))?;
+		Ok(vc.map(Into::into))
+	}
+
+	fn get_trail(
+		&self,
+		vc_fingerprint: Bytes,
+		cursor: Option<u32>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<TrailQueryResult<AccountId, BlockNumber, Hash>>> {
+		let filter =
+			TrailFilter { status: None, account_id: None, from_block: None, to_block: None };
+		self.query_trail(vc_fingerprint, filter, cursor, limit, at)
+	}
+
+	fn query_trail(
+		&self,
+		vc_fingerprint: Bytes,
+		filter: TrailFilter<AccountId, BlockNumber>,
+		cursor: Option<u32>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<TrailQueryResult<AccountId, BlockNumber, Hash>>> {
+		let api = self.client.runtime_api();
+		let at = self.block_id(at);
+
+		let page = api
+			.query_trail(&at, vc_fingerprint.to_vec(), filter, cursor, page_limit(limit)?)
+			.map_err(|e| runtime_error("Unable to query verifiable credential trail.", e))?;
+		Ok(page.map(Into::into))
+	}
+
+	fn credential_status(
+		&self,
+		vc_fingerprint: Bytes,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<CredentialStatus<AccountId, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = self.block_id(at);
+
+		let report = api
+			.credential_status(&at, vc_fingerprint.to_vec())
+			.map_err(|e| runtime_error("Unable to query verifiable credential status.", e))?;
+		Ok(report.map(Into::into))
+	}
+
+	fn list_dids_by_account(
+		&self,
+		account_id: AccountId,
+		start_after: Option<String>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<String>> {
+		let api = self.client.runtime_api();
+		let at = self.block_id(at);
+
+		let dids = api
+			.dids_by_account(
+				&at,
+				account_id,
+				start_after.map(String::into_bytes),
+				page_limit(limit)?,
+			)
+			.map_err(|e| runtime_error("Unable to list DID Documents.", e))?;
+		Ok(dids.into_iter().map(utf8).collect())
+	}
+
+	fn subscribe_did(
+		&self,
+		sink: SubscriptionSink,
+		did_uri: String,
+		blocks: Option<BlockSelection>,
+	) -> SubscriptionResult {
+		let did_uri = did_uri.into_bytes();
+		let keys = self
+			.client
+			.runtime_api()
+			.did_storage_keys(&self.block_id(None), did_uri.clone())
+			.map_err(|e| runtime_error("Unable to read DID Document storage keys.", e));
+
+		let client = self.client.clone();
+		self.subscribe(sink, keys, blocks.unwrap_or(BlockSelection::Best), move |block| {
+			let did = client.runtime_api().resolve_did(&BlockId::hash(block), did_uri.clone())?;
+			Ok(did.map(Into::into))
+		});
+		Ok(())
+	}
+
+	fn subscribe_credential(
+		&self,
+		sink: SubscriptionSink,
+		vc_fingerprint: Bytes,
+		blocks: Option<BlockSelection>,
+	) -> SubscriptionResult {
+		let vc_fingerprint = vc_fingerprint.to_vec();
+		let keys = self
+			.client
+			.runtime_api()
+			.credential_storage_keys(&self.block_id(None), vc_fingerprint.clone())
+			.map_err(|e| runtime_error("Unable to read verifiable credential storage keys.", e));
+
+		let client = self.client.clone();
+		self.subscribe(sink, keys, blocks.unwrap_or(BlockSelection::Best), move |block| {
+			let api = client.runtime_api();
+			let at = BlockId::hash(block);
+			let credential = api.resolve_verifiable_credential(&at, vc_fingerprint.clone())?;
+			let status = api.credential_status(&at, vc_fingerprint.clone())?;
+			Ok(CredentialState {
+				credential: credential.map(Into::into),
+				status: status.map(Into::into),
+			})
+		});
+		Ok(())
+	}
+}
+
+impl<C, Block, TrailMetadataSize> Verifiable<C, Block, TrailMetadataSize>
+where
+	Block: BlockT,
+	C: HeaderBackend<Block> + BlockchainEvents<Block> + Send + Sync + 'static,
+{
+	/// Block to call the runtime API at, defaulting to the best block.
+	fn block_id(&self, at: Option<Block::Hash>) -> BlockId<Block> {
+		BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+	}
+
+	/// Push the state read by `read` at the current block, then at every following block whose
+	/// state differs from the last one pushed. Blocks are only read when one of the storage `keys`
+	/// changed in them, or for finalized blocks, in one of the blocks they finalized.
+	fn subscribe<State, F>(
+		&self,
+		mut sink: SubscriptionSink,
+		keys: Result<Vec<Vec<u8>>, JsonRpseeError>,
+		blocks: BlockSelection,
+		read: F,
+	) where
+		State: Clone + PartialEq + Serialize + Send + 'static,
+		F: Fn(Block::Hash) -> Result<State, sp_api::ApiError> + Send + 'static,
+	{
+		let keys: Vec<StorageKey> = match keys {
+			Ok(keys) if keys.is_empty() => {
+				let _ = sink.reject(JsonRpseeError::from(CallError::Custom(ErrorObject::owned(
+					Error::InvalidKey.into(),
+					"Invalid subscription key.",
+					Some("DID URI or fingerprint exceeds its size bound"),
+				))));
+				return
+			},
+			Ok(keys) => keys.into_iter().map(StorageKey).collect(),
+			Err(e) => {
+				let _ = sink.reject(e);
+				return
+			},
+		};
+
+		let changes = match self.client.storage_changes_notification_stream(Some(&keys), None) {
+			Ok(changes) => changes.map(|notification| notification.block),
+			Err(e) => {
+				let _ = sink.reject(JsonRpseeError::from(CallError::Custom(ErrorObject::owned(
+					Error::RuntimeError.into(),
+					"Unable to watch storage changes.",
+					Some(e.to_string()),
+				))));
+				return
+			},
+		};
+
+		let info = self.client.info();
+		let (start, blocks) = match blocks {
+			BlockSelection::Best => (info.best_hash, changes.boxed()),
+			BlockSelection::Finalized => {
+				let client = self.client.clone();
+				let finality = self.client.finality_notification_stream();
+				(info.finalized_hash, finalized_changes(client, changes, finality).boxed())
+			},
+		};
+
+		let mut last = None;
+		let updates = stream::once(future::ready(start)).chain(blocks).filter_map(move |block| {
+			let update = match read(block) {
+				Ok(state) if last.as_ref() != Some(&state) => {
+					last = Some(state.clone());
+					Some(Update { block, state })
+				},
+				Ok(_) => None,
+				Err(e) => {
+					log::warn!(
+						target: "verifiable-rpc",
+						"Unable to read subscribed state at block {:?}: {}",
+						block,
+						e,
+					);
+					None
+				},
+			};
+			future::ready(update)
+		});
+
+		let fut = async move {
+			sink.pipe_from_stream(updates).await;
+		};
+		self.executor.spawn("verifiable-rpc-subscription", Some("rpc"), fut.boxed());
+	}
+}
+
+/// Block event followed by a finalized subscription.
+enum BlockEvent<Hash> {
+	/// A subscribed storage key changed in the block.
+	Changed(Hash),
+	/// The block was finalized along with the blocks of its tree route.
+	Finalized(Hash, Arc<[Hash]>),
+}
+
+/// Finalized blocks that finalize at least one of the blocks in `changes`, or the latest finalized
+/// block for changes notified after their block was finalized. Changed blocks are tracked with
+/// their number until a finalized block reaches it, so blocks of abandoned forks are dropped once
+/// finality passes them.
+fn finalized_changes<C, Block>(
+	client: Arc<C>,
+	changes: impl Stream<Item = Block::Hash> + Send + 'static,
+	finality: FinalityNotifications<Block>,
+) -> impl Stream<Item = Block::Hash> + Send
+where
+	Block: BlockT,
+	C: HeaderBackend<Block> + Send + Sync + 'static,
+{
+	let mut changed = HashMap::new();
+	stream::select(
+		changes.map(BlockEvent::Changed),
+		finality
+			.map(|notification| BlockEvent::Finalized(notification.hash, notification.tree_route)),
+	)
+	.filter_map(move |event| {
+		let finalized = match event {
+			BlockEvent::Changed(block) => match (client.number(block), client.info()) {
+				// Blocks finalized on import are notified as finalized before their changes
+				(Ok(Some(number)), info) if number <= info.finalized_number =>
+					Some(info.finalized_hash),
+				(Ok(Some(number)), _) => {
+					changed.insert(block, number);
+					None
+				},
+				_ => None,
+			},
+			BlockEvent::Finalized(hash, tree_route) => {
+				let mut finalizes_change = false;
+				for block in tree_route.iter().chain(std::iter::once(&hash)) {
+					finalizes_change |= changed.remove(block).is_some();
+				}
+				if let Ok(Some(number)) = client.number(hash) {
+					changed.retain(|_, changed_number| *changed_number > number);
+				}
+				finalizes_change.then_some(hash)
+			},
+		};
+		future::ready(finalized)
+	})
+}
+
+/// Ensure a page limit is within `1..=MAX_PAGE_LIMIT`.
+fn page_limit(limit: u32) -> RpcResult<u32> {
+	if limit == 0 || limit > MAX_PAGE_LIMIT {
+		return Err(CallError::Custom(ErrorObject::owned(
+			Error::InvalidLimit.into(),
+			"Invalid page limit.",
+			Some(format!("limit must be between 1 and {}", MAX_PAGE_LIMIT)),
+		))
+		.into())
+	}
+	Ok(limit)
+}
+
+fn runtime_error(message: &'static str, e: sp_api::ApiError) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(Error::RuntimeError.into(), message, Some(e.to_string())))
+		.into()
+}
+
+fn utf8(bytes: Vec<u8>) -> String {
+	String::from_utf8_lossy(&bytes).into_owned()
+}
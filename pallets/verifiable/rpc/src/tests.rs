@@ -0,0 +1,100 @@
+use super::*;
+use codec::{Decode, Encode};
+use pallet_verifiable::VerifiableCredentialLog;
+use sp_core::{ConstU32, H256};
+
+type Page = TrailPage<u64, u64, H256, ConstU32<64>>;
+
+fn call_error(e: JsonRpseeError) -> ErrorObject<'static> {
+	match e {
+		JsonRpseeError::Call(CallError::Custom(e)) => e,
+		e => panic!("unexpected error {:?}", e),
+	}
+}
+
+fn trail_page() -> Page {
+	Page {
+		entries: vec![(
+			3,
+			VerifiableCredentialLog {
+				account_id: Some(1),
+				status: VerifiableCredentialStatus::Scanned,
+				block_number: Some(7),
+				timestamp: Some(1_000),
+				metadata: Some(b"warehouse-4".to_vec().try_into().unwrap()),
+				evidence_hash: Some(H256::repeat_byte(0xab)),
+				role: None,
+			},
+		)],
+		next_cursor: Some(4),
+		summary: TrailSummary {
+			current_status: Some(VerifiableCredentialStatus::Scanned),
+			active: Some(true),
+			trail_length: 4,
+			archived_entries: 0,
+			last_block_number: Some(7),
+		},
+	}
+}
+
+mod errors {
+	use super::*;
+
+	#[test]
+	fn maps_runtime_errors() {
+		let api_error = sp_api::ApiError::FailedToDecodeReturnValue {
+			function: "VerifiableApi_query_trail",
+			error: codec::Error::from("Not enough data to fill buffer"),
+		};
+		let expected_data = serde_json::to_string(&api_error.to_string()).unwrap();
+
+		let e =
+			call_error(runtime_error("Unable to query verifiable credential trail.", api_error));
+		assert_eq!(e.code(), i32::from(Error::RuntimeError));
+		assert_eq!(e.message(), "Unable to query verifiable credential trail.");
+		assert_eq!(e.data().map(|data| data.get()), Some(expected_data.as_str()));
+	}
+
+	#[test]
+	fn rejects_out_of_range_limits() {
+		assert_eq!(page_limit(1).unwrap(), 1);
+		assert_eq!(page_limit(MAX_PAGE_LIMIT).unwrap(), MAX_PAGE_LIMIT);
+
+		for limit in [0, MAX_PAGE_LIMIT + 1] {
+			let e = call_error(page_limit(limit).unwrap_err());
+			assert_eq!(e.code(), i32::from(Error::InvalidLimit));
+			assert_eq!(e.message(), "Invalid page limit.");
+		}
+	}
+}
+
+mod decoding {
+	use super::*;
+
+	#[test]
+	fn trail_query_result() {
+		let encoded = Some(trail_page()).encode();
+		let page = Option::<Page>::decode(&mut &encoded[..]).unwrap();
+
+		let result: Option<TrailQueryResult<u64, u64, H256>> = page.map(Into::into);
+		let json = serde_json::to_value(result).unwrap();
+		assert_eq!(json["nextCursor"], 4);
+		assert_eq!(json["entries"][0]["sequence"], 3);
+		assert_eq!(json["entries"][0]["metadata"], "warehouse-4");
+		assert_eq!(json["entries"][0]["evidenceHash"], format!("0x{}", "ab".repeat(32)));
+		assert_eq!(json["summary"]["trailLength"], 4);
+	}
+
+	#[test]
+	fn truncated_trail_page() {
+		let encoded = Some(trail_page()).encode();
+		assert!(Option::<Page>::decode(&mut &encoded[..encoded.len() - 1]).is_err());
+	}
+
+	#[test]
+	fn metadata() {
+		assert_eq!(Metadata::from(b"lot 12".to_vec()), Metadata::Text("lot 12".into()));
+		assert_eq!(Metadata::from(vec![0xff, 0x00]), Metadata::Bytes(Bytes(vec![0xff, 0x00])));
+		assert_eq!(serde_json::to_value(Metadata::from(vec![0xff, 0x00])).unwrap(), "0xff00");
+	}
+}
@@ -0,0 +1,123 @@
+//! Runtime API definition for the verifiable pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_verifiable::{
+	BatchCredentialStatus, CredentialStatusReport, DIDResolution, TrailFilter, TrailPage,
+	VerifiableCredentialLog, VerifiableCredentialResolution,
+};
+use sp_core::Get;
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+	pub trait VerifiableApi<Hash, AccountId, BlockNumber, TrailMetadataSize>
+	where
+		Hash: Codec + Clone + PartialEq + sp_std::fmt::Debug,
+		AccountId: Codec + Clone + PartialEq + sp_std::fmt::Debug,
+		BlockNumber: Codec + Clone + PartialEq + sp_std::fmt::Debug + PartialOrd,
+		TrailMetadataSize: Get<u32>,
+	{
+		/// Resolve a DID Document with its `created` and `updated` metadata rendered as ISO 8601.
+		/// Returns `None` if the DID does not exist.
+		fn resolve_did(did_uri: Vec<u8>) -> Option<DIDResolution<AccountId, BlockNumber>>;
+
+		/// Resolve a verifiable credential with its `created` and `updated` metadata rendered as
+		/// ISO 8601. Returns `None` if the verifiable credential does not exist.
+		fn resolve_verifiable_credential(
+			vc_fingerprint: Vec<u8>,
+		) -> Option<VerifiableCredentialResolution<AccountId, BlockNumber>>;
+
+		/// Validity and current trail status of a verifiable credential, including revoked and
+		/// deleted ones. Returns `None` if the verifiable credential was never anchored.
+		fn credential_status(
+			vc_fingerprint: Vec<u8>,
+		) -> Option<CredentialStatusReport<AccountId, BlockNumber>>;
+
+		/// Page through the DID URIs last created or updated by an account.
+		fn dids_by_account(
+			account_id: AccountId,
+			start_after: Option<Vec<u8>>,
+			limit: u32,
+		) -> Vec<Vec<u8>>;
+
+		/// Storage keys holding the state of a DID Document. Empty if the DID URI is too long.
+		fn did_storage_keys(did_uri: Vec<u8>) -> Vec<Vec<u8>>;
+
+		/// Storage keys holding the state of a verifiable credential. Empty if the fingerprint is
+		/// too long.
+		fn credential_storage_keys(vc_fingerprint: Vec<u8>) -> Vec<Vec<u8>>;
+
+		/// Verify an inclusion proof of a verifiable credential fingerprint against the merkle
+		/// root of a credential batch. Returns `None` if the batch does not exist.
+		fn verify_batch_credential(
+			batch_id: u64,
+			vc_fingerprint: Vec<u8>,
+			proof: Vec<Hash>,
+		) -> Option<BatchCredentialStatus>;
+
+		/// Page through the verifiable credential fingerprints issued by a DID.
+		fn credentials_by_issuer_did(
+			did_uri: Vec<u8>,
+			start_after: Option<Vec<u8>>,
+			limit: u32,
+		) -> Vec<Vec<u8>>;
+
+		/// Page through the verifiable credential fingerprints issued to a DID.
+		fn credentials_by_subject_did(
+			did_uri: Vec<u8>,
+			start_after: Option<Vec<u8>>,
+			limit: u32,
+		) -> Vec<Vec<u8>>;
+
+		/// Page through the verifiable credential fingerprints bound to a holder public key.
+		fn credentials_by_holder(
+			public_key: Vec<u8>,
+			start_after: Option<Vec<u8>>,
+			limit: u32,
+		) -> Vec<Vec<u8>>;
+
+		/// Page through the trail of a verifiable credential starting at the `start` sequence
+		/// number.
+		fn get_verifiable_credential_trail(
+			vc_fingerprint: Vec<u8>,
+			start: u32,
+			limit: u32,
+		) -> Vec<VerifiableCredentialLog<AccountId, BlockNumber, Hash, TrailMetadataSize>>;
+
+		/// Custom status vocabulary registered by an issuer, as status id to label.
+		fn custom_status_vocabulary(issuer: AccountId) -> Vec<(u16, Vec<u8>)>;
+
+		/// Query the trail of a verifiable credential from the `cursor` sequence number, returning
+		/// at most `limit` entries matching the filter along with the current status summary.
+		/// Returns `None` if the verifiable credential has neither metadata nor a trail.
+		fn query_trail(
+			vc_fingerprint: Vec<u8>,
+			filter: TrailFilter<AccountId, BlockNumber>,
+			cursor: Option<u32>,
+			limit: u32,
+		) -> Option<TrailPage<AccountId, BlockNumber, Hash, TrailMetadataSize>>;
+
+		/// Verify that a historical trail entry belongs to an archived trail segment, using a
+		/// merkle proof built from the entries kept in the offchain indexed database.
+		fn verify_archived_trail_log(
+			vc_fingerprint: Vec<u8>,
+			segment: u32,
+			sequence: u32,
+			vc_log: VerifiableCredentialLog<AccountId, BlockNumber, Hash, TrailMetadataSize>,
+			proof: Vec<Hash>,
+		) -> bool;
+
+		/// Walk the split and merge ancestry of a verifiable credential, returning at most
+		/// `limit` fingerprints.
+		fn credential_ancestors(vc_fingerprint: Vec<u8>, limit: u32) -> Vec<Vec<u8>>;
+
+		/// Walk the split and merge descendants of a verifiable credential, returning at most
+		/// `limit` fingerprints.
+		fn credential_descendants(vc_fingerprint: Vec<u8>, limit: u32) -> Vec<Vec<u8>>;
+
+		/// Fingerprints a verifiable credential was migrated through, ordered from the original
+		/// to the latest one and holding at most `limit` fingerprints.
+		fn credential_migration_chain(vc_fingerprint: Vec<u8>, limit: u32) -> Vec<Vec<u8>>;
+	}
+}
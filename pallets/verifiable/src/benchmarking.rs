@@ -1,8 +1,13 @@
 use super::*;
 use crate::Pallet as Verifiable;
+use codec::Encode;
 use frame_benchmarking::{account as benchmark_account, benchmarks, impl_benchmark_test_suite};
-use frame_support::BoundedVec;
+use frame_support::{
+	traits::{Currency, EnsureOrigin},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, Zero};
 
 use sp_std::prelude::*;
 
@@ -15,12 +20,18 @@ pub fn get_account<T: Config>(name: &'static str) -> T::AccountId {
 	account
 }
 pub fn origin<T: Config>(name: &'static str) -> RawOrigin<T::AccountId> {
-	RawOrigin::Signed(get_account::<T>(name))
+	let account = get_account::<T>(name);
+	T::Currency::make_free_balance_be(&account, BalanceOf::<T>::max_value() / 2u32.into());
+	RawOrigin::Signed(account)
 }
 
 const DID_URI: &str = "did:aloo:0x1234567890123456789012345678901234567890";
 const VC_FINGERPRINT: &str = "vc_fingerprint";
 
+pub fn fingerprint<T: Config>(content: &[u8]) -> BoundedVec<u8, T::VCFingerPrintSize> {
+	Verifiable::<T>::fingerprint_of(content).try_into().unwrap()
+}
+
 pub fn prepare_benchmark_data_did<T: Config>() {
 	let did_uri: BoundedVec<u8, T::DIDURISize> = DID_URI.as_bytes().to_vec().try_into().unwrap();
 
@@ -57,8 +68,7 @@ pub fn prepare_benchmark_data_did<T: Config>() {
 }
 
 pub fn prepare_benchmark_verifiable_credential<T: Config>() {
-	let vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize> =
-		"vc_fingerprint".as_bytes().to_vec().try_into().unwrap();
+	let vc_fingerprint = fingerprint::<T>(VC_FINGERPRINT.as_bytes());
 	let public_key: BoundedVec<u8, T::PublicKeySize> = vec![
 		0, 1, 217, 200, 51, 244, 152, 125, 173, 92, 30, 224, 60, 141, 221, 44, 65, 132, 45, 94,
 		199, 150, 116, 108, 95, 18, 118, 246, 86, 167, 64, 132, 76,
@@ -69,6 +79,9 @@ pub fn prepare_benchmark_verifiable_credential<T: Config>() {
 		account_id: Some(get_account::<T>("BOB")),
 		public_key: public_key.clone(),
 		active: Some(true),
+		expiry_block_number: None,
+		issuer_did: None,
+		subject_did: None,
 	};
 	Verifiable::<T>::create_verifiable_credential(
 		origin::<T>("ALICE").into(),
@@ -78,6 +91,33 @@ pub fn prepare_benchmark_verifiable_credential<T: Config>() {
 	.unwrap();
 }
 
+pub fn prepare_benchmark_device<T: Config>() -> DevicePublicKey {
+	prepare_benchmark_data_did::<T>();
+	let did_uri: BoundedVec<u8, T::DIDURISize> = DID_URI.as_bytes().to_vec().try_into().unwrap();
+	let device_key = DevicePublicKey::from_raw([1; 32]);
+	let label: BoundedVec<u8, T::DeviceLabelSize> =
+		vec![0u8; T::DeviceLabelSize::get() as usize].try_into().unwrap();
+	let allowed_statuses: BoundedVec<VerifiableCredentialStatus, T::MaxDeviceStatuses> =
+		vec![VerifiableCredentialStatus::Created].try_into().unwrap();
+	Verifiable::<T>::register_device(
+		origin::<T>("BOB").into(),
+		did_uri,
+		device_key,
+		label,
+		allowed_statuses,
+	)
+	.unwrap();
+	device_key
+}
+
+pub fn prepare_benchmark_role<T: Config>() {
+	let name: BoundedVec<u8, T::RoleNameSize> =
+		vec![0u8; T::RoleNameSize::get() as usize].try_into().unwrap();
+	let allowed_statuses: BoundedVec<VerifiableCredentialStatus, T::MaxRoleStatuses> =
+		vec![VerifiableCredentialStatus::Created].try_into().unwrap();
+	Verifiable::<T>::register_role(origin::<T>("ALICE").into(), 1, name, allowed_statuses).unwrap();
+}
+
 benchmarks! {
 
 	create_did {
@@ -115,6 +155,7 @@ benchmarks! {
 
 	}: _(origin::<T>("ALICE"),did_uri.clone(), did_input)
 	verify {
+		let timestamp = T::TimeProvider::now().as_millis().saturated_into::<u64>();
 		assert!(DIDDocument::<T>::get(&did_uri).is_some());
 		assert!(DIDDocument::<T>::get(&did_uri).unwrap() == DID {
 			signatures: sig,
@@ -122,14 +163,17 @@ benchmarks! {
 			did_document_metadata: Some(did_document_metadata),
 			block_number: 1u32.into(),
 			updated_block_number: 1u32.into(),
+			timestamp,
+			updated_timestamp: timestamp,
 			did_ref: None,
 			sender_account_id: get_account::<T>("BOB"),
 
 		});
 		assert_last_event::<T>(Event::<T>::DIDDocumentCreated {
-			did_uri,
+			did_uri: did_uri.clone(),
 			account_id: get_account::<T>("ALICE"),
 			created_block_number: 1u32.into(),
+			did_document_hash: T::Hashing::hash_of(&DIDDocument::<T>::get(&did_uri).unwrap()),
 		}.into());
 	}
 
@@ -165,7 +209,7 @@ benchmarks! {
 			sender_account_id: get_account::<T>("BOB"),
 		};
 
-	}: _(origin::<T>("ALICE"), did_uri.clone(), did_input)
+	}: _(origin::<T>("BOB"), did_uri.clone(), did_input)
 	verify {
 		assert!(DIDDocument::<T>::get(&did_uri).is_some());
 
@@ -174,9 +218,10 @@ benchmarks! {
 		assert_eq!(DIDDocument::<T>::get(&did_uri).unwrap().did_document_metadata, Some(did_document_metadata));
 		assert_eq!(DIDDocument::<T>::get(&did_uri).unwrap().sender_account_id , get_account::<T>("BOB"));
 		assert_last_event::<T>(Event::<T>::DIDDocumentUpdated {
-			did_uri,
-			account_id: get_account::<T>("ALICE"),
+			did_uri: did_uri.clone(),
+			account_id: get_account::<T>("BOB"),
 			updated_block_number: 1u32.into(),
+			did_document_hash: T::Hashing::hash_of(&DIDDocument::<T>::get(&did_uri).unwrap()),
 		}.into());
 	}
 
@@ -185,32 +230,36 @@ benchmarks! {
 		let alice: T::AccountId = get_account::<T>("ALICE");
 		let did_uri: BoundedVec<u8, T::DIDURISize> = DID_URI.as_bytes().to_vec().try_into().unwrap();
 
-	}: _(origin::<T>("ALICE"),did_uri.clone())
+	}: _(origin::<T>("BOB"),did_uri.clone())
 	verify {
 		assert!(DIDDocument::<T>::get(&did_uri).is_none());
 		assert_last_event::<T>(Event::<T>::DIDDocumentRevoked {
 			did_uri,
-			account_id: get_account::<T>("ALICE"),
+			account_id: get_account::<T>("BOB"),
 			revoked_block_number: 1u32.into(),
 		}.into());
 	}
 
 	create_verifiable_credential{
-		let vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize> = VC_FINGERPRINT.as_bytes().to_vec().try_into().unwrap();
+		let vc_fingerprint = fingerprint::<T>(VC_FINGERPRINT.as_bytes());
 		let public_key: BoundedVec<u8, T::PublicKeySize> = vec![
 			0, 1, 217, 200, 51, 244, 152, 125, 173, 92, 30, 224, 60, 141, 221, 44, 65, 132, 45,
 			94, 199, 150, 116, 108, 95, 18, 118, 246, 86, 167, 64, 132, 76,
 		]
 			.try_into()
 			.unwrap();
-		let vc_metadata_input : VerifiableCredentialMetadataPayload<T::AccountId, T::PublicKeySize>= VerifiableCredentialMetadataPayload {
+		let vc_metadata_input : VerifiableCredentialMetadataPayload<T::AccountId, T::BlockNumber, T::PublicKeySize, T::DIDURISize>= VerifiableCredentialMetadataPayload {
 			account_id: Some(get_account::<T>("BOB")),
 			public_key: public_key.clone(),
 			active: Some(true),
+			expiry_block_number: None,
+			issuer_did: None,
+			subject_did: None,
 		};
 
 	}: _(origin::<T>("ALICE"), vc_fingerprint.clone(),vc_metadata_input.clone())
 	verify {
+		let timestamp = T::TimeProvider::now().as_millis().saturated_into::<u64>();
 		assert!(VerifiableCredential::<T>::get(&vc_fingerprint).is_some());
 		assert!(VerifiableCredential::<T>::get(&vc_fingerprint).unwrap() == VerifiableCredentialMetadata {
 			account_id: Some(get_account::<T>("BOB")),
@@ -218,28 +267,38 @@ benchmarks! {
 			active: Some(true),
 			block_number: 1u32.into(),
 			updated_block_number: 1u32.into(),
+			timestamp,
+			updated_timestamp: timestamp,
+			issuer: get_account::<T>("ALICE"),
+			expiry_block_number: None,
+			issuer_did: None,
+			subject_did: None,
 		});
 
 		assert_last_event::<T>(Event::<T>::VerifiableCredentialFingerPrintCreated {
-			vc_fingerprint,
+			vc_fingerprint: vc_fingerprint.clone(),
 			account_id: get_account::<T>("ALICE"),
 			created_block_number: 1u32.into(),
+			metadata_hash: T::Hashing::hash_of(&VerifiableCredential::<T>::get(&vc_fingerprint).unwrap()),
 		}.into());
 	}
 
 	update_verifiable_credential{
 		prepare_benchmark_verifiable_credential::<T>();
-		let vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize> = VC_FINGERPRINT.as_bytes().to_vec().try_into().unwrap();
+		let vc_fingerprint = fingerprint::<T>(VC_FINGERPRINT.as_bytes());
 		let public_key: BoundedVec<u8, T::PublicKeySize> = vec![
 			10, 10, 217, 200, 51, 244, 152, 125, 173, 92, 30, 224, 60, 141, 221, 44, 65, 132, 45,
 			94, 199, 150, 116, 108, 95, 18, 118, 246, 86, 167, 64, 132, 76,
 		]
 		.try_into()
 		.unwrap();
-		let vc_metadata_input : VerifiableCredentialMetadataPayload<T::AccountId, T::PublicKeySize>= VerifiableCredentialMetadataPayload {
+		let vc_metadata_input : VerifiableCredentialMetadataPayload<T::AccountId, T::BlockNumber, T::PublicKeySize, T::DIDURISize>= VerifiableCredentialMetadataPayload {
 			account_id: Some(get_account::<T>("BOB")),
 			public_key: public_key.clone(),
 			active: Some(true),
+			expiry_block_number: None,
+			issuer_did: None,
+			subject_did: None,
 		};
 
 	}: _(origin::<T>("ALICE"), vc_fingerprint.clone(),vc_metadata_input.clone())
@@ -249,15 +308,19 @@ benchmarks! {
 		assert_eq!(VerifiableCredential::<T>::get(&vc_fingerprint).unwrap().public_key, public_key);
 		assert_eq!(VerifiableCredential::<T>::get(&vc_fingerprint).unwrap().active, Some(true));
 		assert_last_event::<T>(Event::<T>::VerifiableCredentialFingerPrintUpdated {
-			vc_fingerprint,
+			vc_fingerprint: vc_fingerprint.clone(),
 			account_id:  get_account::<T>("ALICE"),
 			updated_block_number: 1u32.into(),
+			issuer: get_account::<T>("ALICE"),
+			metadata_hash: T::Hashing::hash_of(&VerifiableCredential::<T>::get(&vc_fingerprint).unwrap()),
+			previous_active: Some(true),
+			active: Some(true),
 		}.into());
 
 	}
 	revoke_verifiable_credential{
 		prepare_benchmark_verifiable_credential::<T>();
-		let vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize> = VC_FINGERPRINT.as_bytes().to_vec().try_into().unwrap();
+		let vc_fingerprint = fingerprint::<T>(VC_FINGERPRINT.as_bytes());
 
 
 	}: _(origin::<T>("ALICE"),vc_fingerprint.clone())
@@ -267,29 +330,346 @@ benchmarks! {
 				vc_fingerprint,
 				account_id: get_account::<T>("ALICE"),
 				revoked_block_number: 1u32.into(),
+				issuer: get_account::<T>("ALICE"),
+			}.into());
+	}
+
+	delete_verifiable_credential{
+		let n in 1 .. 1_000;
+		prepare_benchmark_verifiable_credential::<T>();
+		let vc_fingerprint = fingerprint::<T>(VC_FINGERPRINT.as_bytes());
+		RateLimitExempt::<T>::insert(get_account::<T>("ALICE"), ());
+		for i in 0 .. n {
+			let status = if i == 0 { VerifiableCredentialStatus::Created } else { VerifiableCredentialStatus::Scanned };
+			Verifiable::<T>::trace_credential(
+				origin::<T>("ALICE").into(),
+				Some(get_account::<T>("BOB")),
+				vc_fingerprint.clone(),
+				status,
+				None,
+				None,
+			)
+			.unwrap();
+		}
+		RateLimitExempt::<T>::remove(get_account::<T>("ALICE"));
+		Verifiable::<T>::revoke_verifiable_credential(origin::<T>("ALICE").into(), vc_fingerprint.clone()).unwrap();
+	}: _(origin::<T>("ALICE"), vc_fingerprint.clone(), n)
+	verify {
+		assert!(VerifiableCredential::<T>::get(&vc_fingerprint).is_none());
+		assert_eq!(VerifiableCredentialTrailHead::<T>::get(&vc_fingerprint), 0);
+		assert!(VerifiableCredentialTrail::<T>::get(&vc_fingerprint, 0).is_none());
+		assert!(VerifiableCredentialTombstone::<T>::contains_key(Verifiable::<T>::tombstone_key(&vc_fingerprint)));
+		assert_last_event::<T>(Event::<T>::VerifiableCredentialDeleted {
+				vc_fingerprint,
+				account_id: get_account::<T>("ALICE"),
+				purged_trail_entries: n,
+				deleted_block_number: 1u32.into(),
+			}.into());
+	}
+
+	anchor_credential_batch{
+		let merkle_root = T::Hashing::hash(VC_FINGERPRINT.as_bytes());
+	}: _(origin::<T>("ALICE"), merkle_root, 1)
+	verify {
+		assert!(CredentialBatch::<T>::get(0).is_some());
+		assert_last_event::<T>(Event::<T>::CredentialBatchAnchored {
+				batch_id: 0,
+				merkle_root,
+				batch_size: 1,
+				account_id: get_account::<T>("ALICE"),
+				created_block_number: 1u32.into(),
+			}.into());
+	}
+
+	revoke_credential_batch{
+		let merkle_root = T::Hashing::hash(VC_FINGERPRINT.as_bytes());
+		Verifiable::<T>::anchor_credential_batch(origin::<T>("ALICE").into(), merkle_root, 1).unwrap();
+	}: _(origin::<T>("ALICE"), 0)
+	verify {
+		assert_eq!(CredentialBatch::<T>::get(0).unwrap().revoked_block_number, Some(1u32.into()));
+		assert_last_event::<T>(Event::<T>::CredentialBatchRevoked {
+				batch_id: 0,
+				account_id: get_account::<T>("ALICE"),
+				revoked_block_number: 1u32.into(),
+			}.into());
+	}
+
+	revoke_batch_credential{
+		let vc_fingerprint = fingerprint::<T>(VC_FINGERPRINT.as_bytes());
+		let merkle_root = Verifiable::<T>::batch_leaf(&vc_fingerprint);
+		Verifiable::<T>::anchor_credential_batch(origin::<T>("ALICE").into(), merkle_root, 1).unwrap();
+	}: _(origin::<T>("ALICE"), 0, vc_fingerprint.clone())
+	verify {
+		assert!(RevokedBatchCredential::<T>::contains_key(0, merkle_root));
+		assert_last_event::<T>(Event::<T>::BatchCredentialRevoked {
+				batch_id: 0,
+				vc_fingerprint,
+				account_id: get_account::<T>("ALICE"),
+				revoked_block_number: 1u32.into(),
+			}.into());
+	}
+
+	split_credential{
+		let n in 1 .. T::MaxLineageSize::get();
+		prepare_benchmark_verifiable_credential::<T>();
+		let vc_fingerprint = fingerprint::<T>(VC_FINGERPRINT.as_bytes());
+		let children: BoundedVec<BoundedVec<u8, T::VCFingerPrintSize>, T::MaxLineageSize> = (0..n)
+			.map(|i| fingerprint::<T>(&i.encode()))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+	}: _(origin::<T>("ALICE"), vc_fingerprint.clone(), children.clone())
+	verify {
+		assert_eq!(VerifiableCredentialCurrentStatus::<T>::get(&vc_fingerprint), Some(VerifiableCredentialStatus::Split));
+		assert_last_event::<T>(Event::<T>::VerifiableCredentialSplit {
+				vc_fingerprint,
+				children,
+				account_id: get_account::<T>("ALICE"),
+				block_number: 1u32.into(),
+			}.into());
+	}
+
+	merge_credentials{
+		let n in 1 .. T::MaxLineageSize::get();
+		prepare_benchmark_verifiable_credential::<T>();
+		let vc_fingerprint = fingerprint::<T>(VC_FINGERPRINT.as_bytes());
+		let parents: BoundedVec<BoundedVec<u8, T::VCFingerPrintSize>, T::MaxLineageSize> = (0..n)
+			.map(|i| fingerprint::<T>(&i.encode()))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		Verifiable::<T>::split_credential(origin::<T>("ALICE").into(), vc_fingerprint, parents.clone()).unwrap();
+		let child = fingerprint::<T>(&n.encode());
+	}: _(origin::<T>("ALICE"), parents.clone(), child.clone())
+	verify {
+		assert!(VerifiableCredential::<T>::get(&child).is_some());
+		assert_last_event::<T>(Event::<T>::VerifiableCredentialsMerged {
+				parents,
+				vc_fingerprint: child,
+				account_id: get_account::<T>("ALICE"),
+				block_number: 1u32.into(),
+			}.into());
+	}
+
+	migrate_credential{
+		prepare_benchmark_verifiable_credential::<T>();
+		let vc_fingerprint = fingerprint::<T>(VC_FINGERPRINT.as_bytes());
+		let new_vc_fingerprint = fingerprint::<T>(b"new_vc_fingerprint");
+	}: _(origin::<T>("ALICE"), vc_fingerprint.clone(), new_vc_fingerprint.clone())
+	verify {
+		assert_eq!(CredentialMigratedTo::<T>::get(&vc_fingerprint), Some(new_vc_fingerprint.clone()));
+		assert_last_event::<T>(Event::<T>::VerifiableCredentialMigrated {
+				vc_fingerprint,
+				new_vc_fingerprint,
+				account_id: get_account::<T>("ALICE"),
+				block_number: 1u32.into(),
 			}.into());
 	}
 
 	trace_credential {
 		prepare_benchmark_verifiable_credential::<T>();
-		let vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize> = VC_FINGERPRINT.as_bytes().to_vec().try_into().unwrap();
+		let vc_fingerprint = fingerprint::<T>(VC_FINGERPRINT.as_bytes());
+		let metadata: BoundedVec<u8, T::TrailMetadataSize> = vec![0u8; T::TrailMetadataSize::get() as usize].try_into().unwrap();
+		let evidence_hash = T::Hashing::hash(VC_FINGERPRINT.as_bytes());
+		prepare_benchmark_role::<T>();
+		Verifiable::<T>::assign_role(origin::<T>("ALICE").into(), RoleMember::Account(get_account::<T>("ALICE")), 1).unwrap();
 	}: _(origin::<T>("ALICE"),
 		Some(get_account::<T>("BOB")),
 		vc_fingerprint.clone(),
-		VerifiableCredentialStatus::Created
+		VerifiableCredentialStatus::Created,
+		Some(metadata.clone()),
+		Some(evidence_hash)
 	)
 	verify {
-		assert!(VerifiableCredentialTrail::<T>::get(&vc_fingerprint).is_some());
-		let results = VerifiableCredentialTrail::<T>::get(&vc_fingerprint).unwrap();
+		assert_eq!(VerifiableCredentialTrailHead::<T>::get(&vc_fingerprint), 1);
+		let results = Verifiable::<T>::verifiable_credential_trail(&vc_fingerprint, 0, 1);
 		assert_eq!(results[0].account_id, Some(get_account::<T>("BOB")));
 		assert_eq!(results[0].status, VerifiableCredentialStatus::Created);
+		assert_eq!(results[0].metadata, Some(metadata));
+		assert_eq!(results[0].evidence_hash, Some(evidence_hash));
+		assert_eq!(results[0].role, Some(1));
 		assert_last_event::<T>(Event::<T>::VerifiableCredentialEvent {
 			vc_fingerprint,
 			origin: get_account::<T>("ALICE"),
 			block_number: 1u32.into(),
 			status: VerifiableCredentialStatus::Created,
+			evidence_hash: Some(evidence_hash),
+			account_id: Some(get_account::<T>("BOB")),
+			issuer: get_account::<T>("ALICE"),
+			sequence: 0,
+			previous_status: None,
+		}.into());
+	}
+
+	register_device {
+		prepare_benchmark_data_did::<T>();
+		let did_uri: BoundedVec<u8, T::DIDURISize> = DID_URI.as_bytes().to_vec().try_into().unwrap();
+		let device_key = DevicePublicKey::from_raw([1; 32]);
+		let label: BoundedVec<u8, T::DeviceLabelSize> = vec![0u8; T::DeviceLabelSize::get() as usize].try_into().unwrap();
+		let allowed_statuses: BoundedVec<VerifiableCredentialStatus, T::MaxDeviceStatuses> = vec![VerifiableCredentialStatus::Created].try_into().unwrap();
+	}: _(origin::<T>("BOB"), did_uri.clone(), device_key, label, allowed_statuses)
+	verify {
+		assert!(Devices::<T>::contains_key(&device_key));
+		assert_last_event::<T>(Event::<T>::DeviceRegistered {
+			did_uri,
+			device_key,
+			account_id: get_account::<T>("BOB"),
+			block_number: 1u32.into(),
+		}.into());
+	}
+
+	remove_device {
+		let device_key = prepare_benchmark_device::<T>();
+	}: _(origin::<T>("BOB"), device_key)
+	verify {
+		assert!(!Devices::<T>::contains_key(&device_key));
+	}
+
+	trace_credential_signed {
+		let device_key = prepare_benchmark_device::<T>();
+		prepare_benchmark_verifiable_credential::<T>();
+		let vc_fingerprint = fingerprint::<T>(VC_FINGERPRINT.as_bytes());
+		let payload = DeviceTracePayload {
+			device_key,
+			genesis_hash: frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero()),
+			nonce: 0,
+			vc_fingerprint: vc_fingerprint.clone(),
+			status: VerifiableCredentialStatus::Created,
+			metadata: Some(vec![0u8; T::TrailMetadataSize::get() as usize].try_into().unwrap()),
+			evidence_hash: Some(T::Hashing::hash(VC_FINGERPRINT.as_bytes())),
+		};
+		// The signature is checked in `validate_unsigned`, not in the call
+		let signature = DeviceSignature::from_raw([0; 64]);
+	}: _(RawOrigin::None, payload, signature)
+	verify {
+		assert_eq!(DeviceNonce::<T>::get(&device_key), 1);
+		assert_last_event::<T>(Event::<T>::DeviceTraceRecorded {
+			vc_fingerprint,
+			device_key,
+			nonce: 0,
+			block_number: 1u32.into(),
 		}.into());
 	}
+
+	archive_verifiable_credential_trail {
+		let n in 1 .. T::MaxArchiveEntries::get();
+		prepare_benchmark_verifiable_credential::<T>();
+		let vc_fingerprint = fingerprint::<T>(VC_FINGERPRINT.as_bytes());
+		RateLimitExempt::<T>::insert(get_account::<T>("ALICE"), ());
+		for i in 0 .. n {
+			let status = if i == 0 { VerifiableCredentialStatus::Created } else { VerifiableCredentialStatus::Scanned };
+			Verifiable::<T>::trace_credential(
+				origin::<T>("ALICE").into(),
+				Some(get_account::<T>("BOB")),
+				vc_fingerprint.clone(),
+				status,
+				None,
+				None,
+			)
+			.unwrap();
+		}
+		RateLimitExempt::<T>::remove(get_account::<T>("ALICE"));
+		frame_system::Pallet::<T>::set_block_number(T::TrailArchiveAge::get() + 1u32.into());
+	}: _(origin::<T>("ALICE"), vc_fingerprint.clone())
+	verify {
+		assert_eq!(VerifiableCredentialTrailTail::<T>::get(&vc_fingerprint), n);
+		assert_eq!(ArchivedTrailSegments::<T>::get(&vc_fingerprint, 0).unwrap().count, n);
+	}
+
+	register_custom_status {
+		let label: BoundedVec<u8, T::CustomStatusLabelSize> = vec![0u8; T::CustomStatusLabelSize::get() as usize].try_into().unwrap();
+	}: _(origin::<T>("ALICE"), 1, label.clone())
+	verify {
+		assert_eq!(CustomStatusVocabulary::<T>::get(get_account::<T>("ALICE"), 1), Some(label.clone()));
+		assert_last_event::<T>(Event::<T>::CustomStatusRegistered {
+			account_id: get_account::<T>("ALICE"),
+			status_id: 1,
+			label,
+			block_number: 1u32.into(),
+		}.into());
+	}
+
+	remove_custom_status {
+		let label: BoundedVec<u8, T::CustomStatusLabelSize> = vec![0u8; T::CustomStatusLabelSize::get() as usize].try_into().unwrap();
+		Verifiable::<T>::register_custom_status(origin::<T>("ALICE").into(), 1, label).unwrap();
+	}: _(origin::<T>("ALICE"), 1)
+	verify {
+		assert!(!CustomStatusVocabulary::<T>::contains_key(get_account::<T>("ALICE"), 1));
+	}
+
+	register_role {
+		let name: BoundedVec<u8, T::RoleNameSize> = vec![0u8; T::RoleNameSize::get() as usize].try_into().unwrap();
+		let allowed_statuses: BoundedVec<VerifiableCredentialStatus, T::MaxRoleStatuses> = vec![VerifiableCredentialStatus::Created].try_into().unwrap();
+	}: _(origin::<T>("ALICE"), 1, name.clone(), allowed_statuses)
+	verify {
+		assert!(Roles::<T>::contains_key(get_account::<T>("ALICE"), 1));
+		assert_last_event::<T>(Event::<T>::RoleRegistered {
+			account_id: get_account::<T>("ALICE"),
+			role_id: 1,
+			name,
+			block_number: 1u32.into(),
+		}.into());
+	}
+
+	remove_role {
+		prepare_benchmark_role::<T>();
+	}: _(origin::<T>("ALICE"), 1)
+	verify {
+		assert!(!Roles::<T>::contains_key(get_account::<T>("ALICE"), 1));
+	}
+
+	assign_role {
+		prepare_benchmark_data_did::<T>();
+		prepare_benchmark_role::<T>();
+		let did_uri: BoundedVec<u8, T::DIDURISize> = DID_URI.as_bytes().to_vec().try_into().unwrap();
+		let member = RoleMember::DID(did_uri);
+	}: _(origin::<T>("ALICE"), member.clone(), 1)
+	verify {
+		assert_eq!(RoleAssignments::<T>::get(get_account::<T>("ALICE"), &member), Some(1));
+		assert_eq!(Roles::<T>::get(get_account::<T>("ALICE"), 1).unwrap().members, 1);
+	}
+
+	unassign_role {
+		prepare_benchmark_role::<T>();
+		let member = RoleMember::Account(get_account::<T>("BOB"));
+		Verifiable::<T>::assign_role(origin::<T>("ALICE").into(), member.clone(), 1).unwrap();
+	}: _(origin::<T>("ALICE"), member.clone())
+	verify {
+		assert!(!RoleAssignments::<T>::contains_key(get_account::<T>("ALICE"), &member));
+		assert_eq!(Roles::<T>::get(get_account::<T>("ALICE"), 1).unwrap().members, 0);
+	}
+
+	set_rate_limit_exemption {
+		let account_id = get_account::<T>("ALICE");
+		let origin = T::RateLimitOrigin::successful_origin();
+	}: _<T::RuntimeOrigin>(origin, account_id.clone(), true)
+	verify {
+		assert!(RateLimitExempt::<T>::contains_key(&account_id));
+		assert_last_event::<T>(Event::<T>::RateLimitExemptionSet { account_id, exempt: true }.into());
+	}
+
+	anchor_credential_content {
+		let n in 1 .. T::MaxCredentialContentSize::get();
+		let content: BoundedVec<u8, T::MaxCredentialContentSize> = vec![0u8; n as usize].try_into().unwrap();
+		let vc_fingerprint = fingerprint::<T>(&content);
+		let public_key: BoundedVec<u8, T::PublicKeySize> = vec![
+			0, 1, 217, 200, 51, 244, 152, 125, 173, 92, 30, 224, 60, 141, 221, 44, 65, 132, 45,
+			94, 199, 150, 116, 108, 95, 18, 118, 246, 86, 167, 64, 132, 76,
+		]
+			.try_into()
+			.unwrap();
+		let vc_metadata_input : VerifiableCredentialMetadataPayload<T::AccountId, T::BlockNumber, T::PublicKeySize, T::DIDURISize>= VerifiableCredentialMetadataPayload {
+			account_id: Some(get_account::<T>("BOB")),
+			public_key,
+			active: Some(true),
+			expiry_block_number: None,
+			issuer_did: None,
+			subject_did: None,
+		};
+	}: _(origin::<T>("ALICE"), vc_fingerprint.clone(), content, vc_metadata_input)
+	verify {
+		assert_eq!(VerifiableCredential::<T>::get(&vc_fingerprint).unwrap().issuer, get_account::<T>("ALICE"));
+	}
 }
 
 impl_benchmark_test_suite!(Verifiable, crate::mock::new_test_ext(), crate::mock::Test);
@@ -9,6 +9,7 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 mod types;
 mod weights;
 
@@ -19,14 +20,29 @@ pub use weights::WeightInfo;
 use frame_support::{
 	dispatch::DispatchResultWithPostInfo,
 	ensure,
-	traits::{Get, StorageVersion},
+	traits::{Currency, Get, ReservableCurrency, StorageVersion, UnixTime},
 	BoundedVec,
 };
 use frame_system::pallet_prelude::*;
 use sp_core::sp_std::str;
-use sp_std::prelude::*;
+use sp_runtime::traits::{CheckedDiv, Hash, SaturatedConversion, Saturating, Verify, Zero};
+use sp_std::{
+	collections::{btree_set::BTreeSet, vec_deque::VecDeque},
+	prelude::*,
+};
+
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+/// Trail entries scanned by a single trail query page
+pub const TRAIL_QUERY_SCAN_LIMIT: u32 = 1000;
+
+/// Prefix of the offchain indexing keys holding archived trail entries
+pub const TRAIL_ARCHIVE_PREFIX: &[u8] = b"verifiable::trail_archive";
 
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type DepositOf<T> = StorageDeposit<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -57,8 +73,106 @@ pub mod pallet {
 		#[pallet::constant]
 		type VCFingerPrintSize: Get<u32>;
 
+		/// Hash function computing the multihash fingerprints of verifiable credentials
+		type FingerprintHasher: FingerprintHasher;
+
+		/// Maximum size of the credential content hashed by `anchor_credential_content`
+		#[pallet::constant]
+		type MaxCredentialContentSize: Get<u32>;
+
 		#[pallet::constant]
 		type DIDURISize: Get<u32>;
+
+		#[pallet::constant]
+		type MaxLineageSize: Get<u32>;
+
+		#[pallet::constant]
+		type TrailMetadataSize: Get<u32>;
+
+		/// Wall-clock time stored next to block numbers
+		type TimeProvider: UnixTime;
+
+		/// Currency reserved for storage deposits
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// Deposit reserved for every stored DID, verifiable credential and trail entry
+		#[pallet::constant]
+		type DepositBase: Get<BalanceOf<Self>>;
+
+		/// Deposit reserved per encoded byte of a stored item
+		#[pallet::constant]
+		type DepositPerByte: Get<BalanceOf<Self>>;
+
+		#[pallet::constant]
+		type CustomStatusLabelSize: Get<u32>;
+
+		/// Custom statuses an issuer may register
+		#[pallet::constant]
+		type MaxCustomStatuses: Get<u32>;
+
+		#[pallet::constant]
+		type RoleNameSize: Get<u32>;
+
+		/// Roles an issuer may define
+		#[pallet::constant]
+		type MaxRoles: Get<u32>;
+
+		/// Statuses a role may append, built-in and custom. Anything below the 9 built-in
+		/// statuses plus `MaxCustomStatuses` caps the custom statuses a role may use.
+		#[pallet::constant]
+		type MaxRoleStatuses: Get<u32>;
+
+		/// Number of blocks after which a trail entry may be archived
+		#[pallet::constant]
+		type TrailArchiveAge: Get<Self::BlockNumber>;
+
+		/// Trail entries archived into a single segment
+		#[pallet::constant]
+		type MaxArchiveEntries: Get<u32>;
+
+		#[pallet::constant]
+		type DeviceLabelSize: Get<u32>;
+
+		/// Statuses a device may append, built-in and custom. Anything below the 9 built-in
+		/// statuses plus `MaxCustomStatuses` caps the custom statuses a device may use.
+		#[pallet::constant]
+		type MaxDeviceStatuses: Get<u32>;
+
+		/// Number of blocks over which device trail events are rate limited
+		#[pallet::constant]
+		type DeviceTracePeriod: Get<Self::BlockNumber>;
+
+		/// Trail events a device may sign per `DeviceTracePeriod`
+		#[pallet::constant]
+		type MaxDeviceTracesPerPeriod: Get<u32>;
+
+		/// Priority of the unsigned device trail event transactions
+		#[pallet::constant]
+		type DeviceTracePriority: Get<TransactionPriority>;
+
+		/// Number of blocks after which the feeless trace quota of an account resets
+		#[pallet::constant]
+		type FeelessTraceEra: Get<Self::BlockNumber>;
+
+		/// Feeless `trace_credential` calls a role holder may sign per `FeelessTraceEra`
+		#[pallet::constant]
+		type MaxFeelessTracesPerEra: Get<u32>;
+
+		/// Number of blocks over which calls are rate limited
+		#[pallet::constant]
+		type RateLimitWindow: Get<Self::BlockNumber>;
+
+		/// Calls an account may sign per `RateLimitWindow`
+		#[pallet::constant]
+		type MaxOperationsPerWindow: Get<u32>;
+
+		/// Trail events an account may append to the verifiable credentials of an issuer per
+		/// `RateLimitWindow`
+		#[pallet::constant]
+		type MaxIssuerTracesPerWindow: Get<u32>;
+
+		/// Origin exempting accounts from rate limits
+		type RateLimitOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 	}
 
 	#[pallet::storage]
@@ -71,12 +185,75 @@ pub mod pallet {
 	>;
 
 	#[pallet::storage]
-	#[pallet::getter(fn get_verifiable_credential_trail)]
-	pub(super) type VerifiableCredentialTrail<T: Config> = StorageMap<
+	pub type DIDsByAccount<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		// AccountId that signed the latest creation or update of the DID
+		T::AccountId,
+		Blake2_128Concat,
+		// DID URI
+		BoundedVec<u8, T::DIDURISize>,
+		(),
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_verifiable_credential_log)]
+	pub(super) type VerifiableCredentialTrail<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		BoundedVec<u8, T::VCFingerPrintSize>,
+		// Sequence number of the log entry
+		Twox64Concat,
+		u32,
+		VerifiableCredentialLog<T::AccountId, T::BlockNumber, T::Hash, T::TrailMetadataSize>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_verifiable_credential_trail_head)]
+	pub type VerifiableCredentialTrailHead<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BoundedVec<u8, T::VCFingerPrintSize>,
+		// Sequence number of the next log entry
+		u32,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_verifiable_credential_trail_tail)]
+	pub type VerifiableCredentialTrailTail<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BoundedVec<u8, T::VCFingerPrintSize>,
+		// Sequence number of the oldest log entry kept in state
+		u32,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_archived_trail_segment)]
+	pub type ArchivedTrailSegments<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		BoundedVec<u8, T::VCFingerPrintSize>,
+		// Segment index
+		Twox64Concat,
+		u32,
+		ArchivedTrailSegment<T::BlockNumber, T::Hash>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_archived_trail_segment_count)]
+	pub type ArchivedTrailSegmentCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::VCFingerPrintSize>, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_verifiable_credential_status)]
+	pub type VerifiableCredentialCurrentStatus<T: Config> = StorageMap<
 		_,
 		Blake2_128Concat,
 		BoundedVec<u8, T::VCFingerPrintSize>,
-		BoundedVec<VerifiableCredentialLog<T::AccountId, T::BlockNumber>, T::MetadataSize>,
+		VerifiableCredentialStatus,
 	>;
 
 	#[pallet::storage]
@@ -87,9 +264,460 @@ pub mod pallet {
 		// VC fingerprint
 		BoundedVec<u8, T::VCFingerPrintSize>,
 		// VC metadata
-		VerifiableCredentialMetadata<T::AccountId, T::BlockNumber, T::PublicKeySize>,
+		VerifiableCredentialMetadata<T::AccountId, T::BlockNumber, T::PublicKeySize, T::DIDURISize>,
+	>;
+
+	#[pallet::storage]
+	pub type CredentialsByIssuerDID<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		// Issuer's DID URI
+		BoundedVec<u8, T::DIDURISize>,
+		Blake2_128Concat,
+		// VC fingerprint
+		BoundedVec<u8, T::VCFingerPrintSize>,
+		(),
+	>;
+
+	#[pallet::storage]
+	pub type CredentialsBySubjectDID<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		// Subject's DID URI
+		BoundedVec<u8, T::DIDURISize>,
+		Blake2_128Concat,
+		// VC fingerprint
+		BoundedVec<u8, T::VCFingerPrintSize>,
+		(),
+	>;
+
+	#[pallet::storage]
+	pub type CredentialsByHolder<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		// Hash of the holder's public key
+		T::Hash,
+		Blake2_128Concat,
+		// VC fingerprint
+		BoundedVec<u8, T::VCFingerPrintSize>,
+		(),
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_verifiable_credential_revocation)]
+	pub type RevokedVerifiableCredential<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BoundedVec<u8, T::VCFingerPrintSize>,
+		VerifiableCredentialRevocation<T::AccountId, T::BlockNumber>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_verifiable_credential_tombstone)]
+	pub type VerifiableCredentialTombstone<T: Config> = StorageMap<
+		_,
+		Identity,
+		// Hash of the deleted VC fingerprint
+		T::Hash,
+		// Deleted block number
+		T::BlockNumber,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_credential_batch_count)]
+	pub type CredentialBatchCount<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_credential_batch)]
+	pub type CredentialBatch<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		// Batch id
+		u64,
+		CredentialBatchMetadata<T::AccountId, T::BlockNumber, T::Hash>,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn get_revoked_batch_credential)]
+	pub type RevokedBatchCredential<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		// Batch id
+		u64,
+		Identity,
+		// Merkle leaf of the revoked VC fingerprint
+		T::Hash,
+		// Revoked block number
+		T::BlockNumber,
+	>;
+
+	#[pallet::storage]
+	pub type CredentialParents<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		// Child VC fingerprint
+		BoundedVec<u8, T::VCFingerPrintSize>,
+		Blake2_128Concat,
+		// Parent VC fingerprint
+		BoundedVec<u8, T::VCFingerPrintSize>,
+		(),
+	>;
+
+	#[pallet::storage]
+	pub type CredentialChildren<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		// Parent VC fingerprint
+		BoundedVec<u8, T::VCFingerPrintSize>,
+		Blake2_128Concat,
+		// Child VC fingerprint
+		BoundedVec<u8, T::VCFingerPrintSize>,
+		(),
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_credential_migrated_to)]
+	pub type CredentialMigratedTo<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		// Migrated VC fingerprint
+		BoundedVec<u8, T::VCFingerPrintSize>,
+		// New VC fingerprint
+		BoundedVec<u8, T::VCFingerPrintSize>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_credential_migrated_from)]
+	pub type CredentialMigratedFrom<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		// New VC fingerprint
+		BoundedVec<u8, T::VCFingerPrintSize>,
+		// Migrated VC fingerprint
+		BoundedVec<u8, T::VCFingerPrintSize>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_did_deposit)]
+	pub type DIDDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::DIDURISize>, DepositOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_credential_deposit)]
+	pub type CredentialDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::VCFingerPrintSize>, DepositOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_trail_deposit)]
+	pub type TrailDeposits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		BoundedVec<u8, T::VCFingerPrintSize>,
+		Twox64Concat,
+		// Trail entry sequence number
+		u32,
+		DepositOf<T>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_custom_status)]
+	pub type CustomStatusVocabulary<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		// Issuer's AccountId
+		T::AccountId,
+		Twox64Concat,
+		// Custom status id
+		u16,
+		// Custom status label
+		BoundedVec<u8, T::CustomStatusLabelSize>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_custom_status_count)]
+	pub type CustomStatusCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_role)]
+	pub type Roles<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		// Issuer's AccountId
+		T::AccountId,
+		Twox64Concat,
+		// Role id
+		u16,
+		Role<T::BlockNumber, T::RoleNameSize, T::MaxRoleStatuses>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_role_count)]
+	pub type RoleCount<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_role_assignment)]
+	pub type RoleAssignments<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		// Issuer's AccountId
+		T::AccountId,
+		Blake2_128Concat,
+		RoleMember<T::AccountId, T::DIDURISize>,
+		// Role id
+		u16,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_device)]
+	pub type Devices<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		DevicePublicKey,
+		Device<
+			T::AccountId,
+			T::BlockNumber,
+			T::DIDURISize,
+			T::DeviceLabelSize,
+			T::MaxDeviceStatuses,
+		>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_device_nonce)]
+	pub type DeviceNonce<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		DevicePublicKey,
+		// Nonce expected in the next payload signed by the device. Kept after the device is
+		// removed so signed payloads cannot be replayed if it is registered again
+		u64,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_device_trace_count)]
+	pub type DeviceTraceCount<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		DevicePublicKey,
+		// Start block number of the current period and the trail events signed in it
+		(T::BlockNumber, u32),
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_feeless_trace_count)]
+	pub type FeelessTraceCount<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		// Era index and the feeless trail events signed in it
+		(T::BlockNumber, u32),
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	pub type FeelessTracer<T: Config> = StorageValue<
+		_,
+		// Account whose feeless trail event is being dispatched
+		T::AccountId,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_account_operations)]
+	pub type AccountOperations<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		// Rate limit window index and the calls signed in it
+		(T::BlockNumber, u32),
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_issuer_traces)]
+	pub type IssuerTraces<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		// Issuer's AccountId
+		T::AccountId,
+		Blake2_128Concat,
+		// Tracer's AccountId
+		T::AccountId,
+		// Rate limit window index and the trail events appended in it
+		(T::BlockNumber, u32),
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_rate_limit_exempt)]
+	pub type RateLimitExempt<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	/// Records created at genesis. DIDs and verifiable credentials reserve their deposits from
+	/// their controller and issuer like records created by extrinsics, so those accounts must be
+	/// endowed by the balances genesis. Their timestamps are zero since no block time exists at
+	/// genesis. Like any newly anchored credential, genesis credentials start without a trail
+	/// and thus without a current status.
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// DIDs created at genesis
+		pub dids: Vec<GenesisDID<T::AccountId>>,
+		/// Verifiable credentials anchored at genesis
+		pub credentials: Vec<GenesisCredential<T::AccountId>>,
+		/// Custom statuses registered in issuer vocabularies at genesis
+		pub custom_statuses: Vec<GenesisCustomStatus<T::AccountId>>,
+		/// Issuer roles registered at genesis
+		pub roles: Vec<GenesisRole<T::AccountId>>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self {
+				dids: Vec::new(),
+				credentials: Vec::new(),
+				custom_statuses: Vec::new(),
+				roles: Vec::new(),
+			}
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			let block_number = <frame_system::Pallet<T>>::block_number();
+
+			for GenesisDID { did_uri, controller, did_document_metadata } in &self.dids {
+				let did_uri: BoundedVec<u8, T::DIDURISize> =
+					did_uri.clone().try_into().expect("DID URI exceeds DIDURISize");
+				assert!(!DIDDocument::<T>::contains_key(&did_uri), "duplicate genesis DID");
+				let did_document = DID {
+					signatures: Default::default(),
+					did_resolution_metadata: None,
+					did_document_metadata: Some(
+						did_document_metadata
+							.clone()
+							.try_into()
+							.expect("DID document metadata exceeds MetadataSize"),
+					),
+					block_number,
+					updated_block_number: block_number,
+					timestamp: 0,
+					updated_timestamp: 0,
+					did_ref: None,
+					sender_account_id: controller.clone(),
+				};
+				let deposit = Pallet::<T>::update_deposit(
+					controller,
+					None,
+					did_uri.len() + did_document.encoded_size(),
+				)
+				.expect("genesis DID controller cannot fund the DID deposit");
+				DIDDeposits::<T>::insert(&did_uri, deposit);
+				DIDsByAccount::<T>::insert(controller, &did_uri, ());
+				DIDDocument::<T>::insert(did_uri, did_document);
+			}
+
+			for GenesisCredential { vc_fingerprint, issuer, public_key, issuer_did, subject_did } in
+				&self.credentials
+			{
+				let vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize> = vc_fingerprint
+					.clone()
+					.try_into()
+					.expect("verifiable credential fingerprint exceeds VCFingerPrintSize");
+				assert!(
+					T::FingerprintHasher::is_fingerprint(&vc_fingerprint),
+					"malformed genesis verifiable credential fingerprint"
+				);
+				assert!(
+					!VerifiableCredential::<T>::contains_key(&vc_fingerprint),
+					"duplicate genesis verifiable credential"
+				);
+				let public_key: BoundedVec<u8, T::PublicKeySize> =
+					public_key.clone().try_into().expect("public key exceeds PublicKeySize");
+				assert!(
+					Pallet::<T>::is_substrate_public_key(public_key.clone()),
+					"invalid public key"
+				);
+				let genesis_did = |did_uri: &Option<Vec<u8>>| {
+					did_uri.clone().map(|did_uri| {
+						let did_uri: BoundedVec<u8, T::DIDURISize> =
+							did_uri.try_into().expect("DID URI exceeds DIDURISize");
+						assert!(
+							DIDDocument::<T>::contains_key(&did_uri),
+							"verifiable credential DID is not a genesis DID"
+						);
+						did_uri
+					})
+				};
+				let verifiable_credential_metadata = VerifiableCredentialMetadata {
+					account_id: None,
+					public_key,
+					block_number,
+					updated_block_number: block_number,
+					timestamp: 0,
+					updated_timestamp: 0,
+					active: Some(true),
+					issuer: issuer.clone(),
+					expiry_block_number: None,
+					issuer_did: genesis_did(issuer_did),
+					subject_did: genesis_did(subject_did),
+				};
+				Pallet::<T>::insert_verifiable_credential(
+					issuer,
+					&vc_fingerprint,
+					verifiable_credential_metadata,
+				)
+				.expect("genesis issuer cannot fund the verifiable credential deposit");
+			}
+
+			for GenesisCustomStatus { issuer, status_id, label } in &self.custom_statuses {
+				assert!(
+					!CustomStatusVocabulary::<T>::contains_key(issuer, status_id),
+					"duplicate genesis custom status"
+				);
+				CustomStatusCount::<T>::mutate(issuer, |count| *count += 1);
+				assert!(
+					CustomStatusCount::<T>::get(issuer) <= T::MaxCustomStatuses::get(),
+					"too many genesis custom statuses"
+				);
+				let label: BoundedVec<u8, T::CustomStatusLabelSize> = label
+					.clone()
+					.try_into()
+					.expect("custom status label exceeds CustomStatusLabelSize");
+				CustomStatusVocabulary::<T>::insert(issuer, status_id, label);
+			}
+
+			for GenesisRole { issuer, role_id, name, allowed_statuses, members } in &self.roles {
+				assert!(!Roles::<T>::contains_key(issuer, role_id), "duplicate genesis role");
+				RoleCount::<T>::mutate(issuer, |count| *count += 1);
+				assert!(
+					RoleCount::<T>::get(issuer) <= T::MaxRoles::get(),
+					"too many genesis roles"
+				);
+				let role = Role {
+					name: name.clone().try_into().expect("role name exceeds RoleNameSize"),
+					allowed_statuses: allowed_statuses
+						.clone()
+						.try_into()
+						.expect("allowed statuses exceed MaxRoleStatuses"),
+					members: members.len() as u32,
+					block_number,
+				};
+				Roles::<T>::insert(issuer, role_id, role);
+				for member in members {
+					let member = RoleMember::Account(member.clone());
+					assert!(
+						!RoleAssignments::<T>::contains_key(issuer, &member),
+						"genesis role member is assigned twice"
+					);
+					RoleAssignments::<T>::insert(issuer, member, role_id);
+				}
+			}
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -98,6 +726,7 @@ pub mod pallet {
 			did_uri: BoundedVec<u8, T::DIDURISize>,
 			account_id: T::AccountId,
 			created_block_number: T::BlockNumber,
+			did_document_hash: T::Hash,
 		},
 
 		/// DID Document Updated
@@ -105,6 +734,7 @@ pub mod pallet {
 			did_uri: BoundedVec<u8, T::DIDURISize>,
 			account_id: T::AccountId,
 			updated_block_number: T::BlockNumber,
+			did_document_hash: T::Hash,
 		},
 
 		/// DID Document revoked
@@ -119,6 +749,7 @@ pub mod pallet {
 			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
 			account_id: T::AccountId,
 			created_block_number: T::BlockNumber,
+			metadata_hash: T::Hash,
 		},
 
 		/// Verifiable credential fingerprint updated
@@ -126,6 +757,10 @@ pub mod pallet {
 			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
 			account_id: T::AccountId,
 			updated_block_number: T::BlockNumber,
+			issuer: T::AccountId,
+			metadata_hash: T::Hash,
+			previous_active: Option<bool>,
+			active: Option<bool>,
 		},
 
 		/// Verifiable credential fingerprint revoked
@@ -133,16 +768,168 @@ pub mod pallet {
 			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
 			account_id: T::AccountId,
 			revoked_block_number: T::BlockNumber,
+			issuer: T::AccountId,
 		},
 
-		/// VerifiableCredentialEvent
-		VerifiableCredentialEvent {
+		/// Verifiable credential metadata and trail deleted
+		VerifiableCredentialDeleted {
 			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
-			origin: T::AccountId,
+			account_id: T::AccountId,
+			purged_trail_entries: u32,
+			deleted_block_number: T::BlockNumber,
+		},
+
+		/// Oldest trail entries of a revoked or expired verifiable credential purged ahead of its
+		/// deletion
+		VerifiableCredentialTrailPurged {
+			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
+			account_id: T::AccountId,
+			purged_trail_entries: u32,
+			remaining_trail_entries: u32,
 			block_number: T::BlockNumber,
-			status: VerifiableCredentialStatus,
 		},
-	}
+
+		/// Credential batch merkle root anchored
+		CredentialBatchAnchored {
+			batch_id: u64,
+			merkle_root: T::Hash,
+			batch_size: u32,
+			account_id: T::AccountId,
+			created_block_number: T::BlockNumber,
+		},
+
+		/// Credential batch revoked
+		CredentialBatchRevoked {
+			batch_id: u64,
+			account_id: T::AccountId,
+			revoked_block_number: T::BlockNumber,
+		},
+
+		/// Single verifiable credential of a credential batch revoked
+		BatchCredentialRevoked {
+			batch_id: u64,
+			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
+			account_id: T::AccountId,
+			revoked_block_number: T::BlockNumber,
+		},
+
+		/// Verifiable credential split into child credentials
+		VerifiableCredentialSplit {
+			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
+			children: BoundedVec<BoundedVec<u8, T::VCFingerPrintSize>, T::MaxLineageSize>,
+			account_id: T::AccountId,
+			block_number: T::BlockNumber,
+		},
+
+		/// Verifiable credentials merged into a child credential
+		VerifiableCredentialsMerged {
+			parents: BoundedVec<BoundedVec<u8, T::VCFingerPrintSize>, T::MaxLineageSize>,
+			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
+			account_id: T::AccountId,
+			block_number: T::BlockNumber,
+		},
+
+		/// Verifiable credential migrated to a new fingerprint
+		VerifiableCredentialMigrated {
+			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
+			new_vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
+			account_id: T::AccountId,
+			block_number: T::BlockNumber,
+		},
+
+		/// Old trail entries pruned into an archived segment
+		VerifiableCredentialTrailArchived {
+			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
+			segment: u32,
+			merkle_root: T::Hash,
+			start: u32,
+			count: u32,
+			block_number: T::BlockNumber,
+		},
+
+		/// Custom status registered in the vocabulary of an issuer
+		CustomStatusRegistered {
+			account_id: T::AccountId,
+			status_id: u16,
+			label: BoundedVec<u8, T::CustomStatusLabelSize>,
+			block_number: T::BlockNumber,
+		},
+
+		/// Custom status removed from the vocabulary of an issuer
+		CustomStatusRemoved {
+			account_id: T::AccountId,
+			status_id: u16,
+			block_number: T::BlockNumber,
+		},
+
+		/// Role registered by an issuer
+		RoleRegistered {
+			account_id: T::AccountId,
+			role_id: u16,
+			name: BoundedVec<u8, T::RoleNameSize>,
+			block_number: T::BlockNumber,
+		},
+
+		/// Role removed by an issuer
+		RoleRemoved { account_id: T::AccountId, role_id: u16, block_number: T::BlockNumber },
+
+		/// Account or DID assigned to a role of an issuer
+		RoleAssigned {
+			account_id: T::AccountId,
+			member: RoleMember<T::AccountId, T::DIDURISize>,
+			role_id: u16,
+			block_number: T::BlockNumber,
+		},
+
+		/// Account or DID unassigned from a role of an issuer
+		RoleUnassigned {
+			account_id: T::AccountId,
+			member: RoleMember<T::AccountId, T::DIDURISize>,
+			role_id: u16,
+			block_number: T::BlockNumber,
+		},
+
+		/// Scanning device registered by a DID
+		DeviceRegistered {
+			did_uri: BoundedVec<u8, T::DIDURISize>,
+			device_key: DevicePublicKey,
+			account_id: T::AccountId,
+			block_number: T::BlockNumber,
+		},
+
+		/// Scanning device removed
+		DeviceRemoved {
+			did_uri: BoundedVec<u8, T::DIDURISize>,
+			device_key: DevicePublicKey,
+			account_id: T::AccountId,
+			block_number: T::BlockNumber,
+		},
+
+		/// Trail event signed by a scanning device
+		DeviceTraceRecorded {
+			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
+			device_key: DevicePublicKey,
+			nonce: u64,
+			block_number: T::BlockNumber,
+		},
+
+		/// Account exempted from rate limits or its exemption revoked
+		RateLimitExemptionSet { account_id: T::AccountId, exempt: bool },
+
+		/// Status appended to the trail of a verifiable credential by `origin` on behalf of the
+		/// traced `account_id`
+		VerifiableCredentialEvent {
+			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
+			origin: T::AccountId,
+			block_number: T::BlockNumber,
+			status: VerifiableCredentialStatus,
+			evidence_hash: Option<T::Hash>,
+			account_id: Option<T::AccountId>,
+			issuer: T::AccountId,
+			sequence: u32,
+			previous_status: Option<VerifiableCredentialStatus>,
+		},
+	}
 
 	#[pallet::error]
 	pub enum Error<T> {
@@ -175,10 +962,127 @@ pub mod pallet {
 
 		/// InvalidPublicKey
 		InvalidPublicKey,
+
+		/// Caller is not the issuer of the verifiable credential
+		NotVerifiableCredentialIssuer,
+
+		/// Verifiable credential is neither revoked nor expired
+		VerifiableCredentialNotRevokedOrExpired,
+
+		/// Verifiable credential fingerprint was deleted and cannot be reused
+		VerifiableCredentialFingerPrintDeleted,
+
+		/// Verifiable credential fingerprint is not a multihash of `FingerprintHasher`
+		MalformedFingerprint,
+
+		/// Verifiable credential fingerprint does not match the hash of the credential content
+		FingerprintMismatch,
+
+		/// Credential batch does not exist
+		CredentialBatchDoesNotExist,
+
+		/// Credential batch is empty
+		EmptyCredentialBatch,
+
+		/// Caller is not the issuer of the credential batch
+		NotCredentialBatchIssuer,
+
+		/// Credential batch or the credential in it is already revoked
+		CredentialBatchRevoked,
+
+		/// First trail status must be `Pending` or `Created`
+		InvalidInitialStatus,
+
+		/// No status may follow `Frozen`
+		VerifiableCredentialFrozen,
+
+		/// No status may follow `Migrated`, `Split` or `Merged`
+		VerifiableCredentialTerminated,
+
+		/// `Resumed` may only follow `Suspended`
+		ResumeWithoutSuspend,
+
+		/// Status may not follow the current status
+		InvalidStatusTransition,
+
+		/// Lineage is empty, repeats a fingerprint or references itself
+		InvalidCredentialLineage,
+
+		/// No trail entry is old enough to be archived
+		NothingToArchive,
+
+		/// Custom status is already registered
+		CustomStatusExists,
+
+		/// Custom status is not registered by the issuer of the verifiable credential
+		CustomStatusNotRegistered,
+
+		/// Issuer registered `MaxCustomStatuses` custom statuses
+		TooManyCustomStatuses,
+
+		/// Role is already registered
+		RoleExists,
+
+		/// Role is not registered by the issuer
+		RoleDoesNotExist,
+
+		/// Issuer registered `MaxRoles` roles
+		TooManyRoles,
+
+		/// Role still has assigned accounts or DIDs
+		RoleHasMembers,
+
+		/// Account or DID is not assigned to a role of the issuer
+		RoleNotAssigned,
+
+		/// Role of the tracer may not append the status to trails of the issuer
+		RoleStatusNotAllowed,
+
+		/// Caller is not the controller of the DID
+		NotDIDController,
+
+		/// Device is already registered
+		DeviceExists,
+
+		/// Device is not registered
+		DeviceDoesNotExist,
+
+		/// Caller is not the controller of the device
+		NotDeviceController,
+
+		/// Device may not append the status to a trail
+		DeviceStatusNotAllowed,
+
+		/// Device payload nonce does not match the device nonce
+		InvalidDeviceNonce,
+
+		/// Device signed too many trail events in the current period
+		DeviceRateLimited,
+
+		/// Device payload was signed for another chain
+		InvalidDeviceGenesisHash,
+
+		/// Caller signed `MaxOperationsPerWindow` calls in the current window
+		AccountRateLimited,
+
+		/// Caller appended `MaxIssuerTracesPerWindow` trail events to the verifiable credentials
+		/// of the issuer in the current window
+		IssuerRateLimited,
+
+		/// Version 1 trail of the verifiable credential is not migrated yet
+		TrailMigrationPending,
+
+		/// `Migrated`, `Split` and `Merged` are only appended by `migrate_credential`,
+		/// `split_credential` and `merge_credentials`
+		LineageStatusReserved,
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: T::BlockNumber) -> Weight {
+			migrations::v2::migrate_trails::<T>(migrations::v2::TRAIL_MIGRATION_STEP)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -189,6 +1093,7 @@ pub mod pallet {
 		/// # Errors
 		/// * `DIDExists` - DID Document already exists
 		/// * `InvalidDIDURI` - DID Document URI is invalid
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
 		#[pallet::weight(T::WeightInfo::create_did_document())]
 		pub fn create_did(
 			origin: OriginFor<T>,
@@ -196,26 +1101,36 @@ pub mod pallet {
 			did_input: DIDMetadataPayload<T::AccountId, T::MetadataSize>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
 
 			let block_number = <frame_system::Pallet<T>>::block_number();
+			let timestamp = Self::now();
 			let did_document = DID {
 				signatures: did_input.signatures,
 				did_resolution_metadata: did_input.did_resolution_metadata,
 				did_document_metadata: did_input.did_document_metadata,
 				block_number,
 				updated_block_number: block_number,
+				timestamp,
+				updated_timestamp: timestamp,
 				did_ref: did_input.did_ref,
 				sender_account_id: did_input.sender_account_id,
 			};
 
 			ensure!(!DIDDocument::<T>::contains_key(&did_uri), Error::<T>::DIDExists);
 
+			let deposit =
+				Self::update_deposit(&who, None, did_uri.len() + did_document.encoded_size())?;
+			let did_document_hash = T::Hashing::hash_of(&did_document);
+			DIDDeposits::<T>::insert(&did_uri, deposit);
+			DIDsByAccount::<T>::insert(&who, &did_uri, ());
 			DIDDocument::<T>::insert(did_uri.clone(), did_document);
 
 			let event = Event::DIDDocumentCreated {
 				did_uri,
 				account_id: who,
 				created_block_number: block_number,
+				did_document_hash,
 			};
 			Self::deposit_event(event);
 
@@ -228,7 +1143,9 @@ pub mod pallet {
 		/// * `did_input` - DID Document
 		/// # Errors
 		/// * `DIDDoesNotExist` - DID Document not exists
+		/// * `NotDIDController` - Caller is not the controller of the DID
 		/// * `InvalidDIDURI` - DID Document URI is invalid
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
 		#[pallet::weight(T::WeightInfo::update_did_document())]
 		pub fn update_did_document(
 			origin: OriginFor<T>,
@@ -236,30 +1153,43 @@ pub mod pallet {
 			did_input: DIDMetadataPayload<T::AccountId, T::MetadataSize>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
 			let block_number = <frame_system::Pallet<T>>::block_number();
 
-			DIDDocument::<T>::mutate(did_uri.clone(), |did| match did {
-				| None => Err(Error::<T>::DIDDoesNotExist),
-				| Some(did) => {
-					let did_document = DID {
-						signatures: did_input.signatures,
-						did_resolution_metadata: did_input.did_resolution_metadata,
-						did_document_metadata: did_input.did_document_metadata,
-						block_number: did.block_number,
-						updated_block_number: block_number,
-						did_ref: did_input.did_ref,
-						sender_account_id: did_input.sender_account_id,
-					};
-
-					*did = did_document;
-					let event = Event::DIDDocumentUpdated {
-						did_uri,
-						account_id: who,
-						updated_block_number: block_number,
-					};
-					Self::deposit_event(event);
-					Ok(())
-				},
+			DIDDocument::<T>::mutate(did_uri.clone(), |did| -> DispatchResult {
+				let did = did.as_mut().ok_or(Error::<T>::DIDDoesNotExist)?;
+				ensure!(did.sender_account_id == who, Error::<T>::NotDIDController);
+				let did_document = DID {
+					signatures: did_input.signatures,
+					did_resolution_metadata: did_input.did_resolution_metadata,
+					did_document_metadata: did_input.did_document_metadata,
+					block_number: did.block_number,
+					updated_block_number: block_number,
+					timestamp: did.timestamp,
+					updated_timestamp: Self::now(),
+					did_ref: did_input.did_ref,
+					sender_account_id: did_input.sender_account_id,
+				};
+
+				let previous = DIDDeposits::<T>::get(&did_uri);
+				DIDsByAccount::<T>::remove(Self::did_indexed_account(&previous, did), &did_uri);
+				let deposit = Self::update_deposit(
+					&who,
+					previous,
+					did_uri.len() + did_document.encoded_size(),
+				)?;
+				DIDDeposits::<T>::insert(&did_uri, deposit);
+				DIDsByAccount::<T>::insert(&who, &did_uri, ());
+				let did_document_hash = T::Hashing::hash_of(&did_document);
+				*did = did_document;
+				let event = Event::DIDDocumentUpdated {
+					did_uri,
+					account_id: who,
+					updated_block_number: block_number,
+					did_document_hash,
+				};
+				Self::deposit_event(event);
+				Ok(())
 			})?;
 
 			Ok(().into())
@@ -270,17 +1200,25 @@ pub mod pallet {
 		/// * `did_uri` - DID URI
 		/// # Errors
 		/// * `DIDDoesNotExist` - DID Document not exists
+		/// * `NotDIDController` - Caller is not the controller of the DID
 		/// * `InvalidDIDURI` - DID Document URI is invalid
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
 		#[pallet::weight(T::WeightInfo::revoke_did_document())]
 		pub fn revoke_did_document(
 			origin: OriginFor<T>,
 			did_uri: BoundedVec<u8, T::DIDURISize>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
+
+			let did = DIDDocument::<T>::get(&did_uri).ok_or(Error::<T>::DIDDoesNotExist)?;
+			ensure!(did.sender_account_id == who, Error::<T>::NotDIDController);
 
-			ensure!(DIDDocument::<T>::contains_key(&did_uri), Error::<T>::DIDDoesNotExist);
 			let block_number = <frame_system::Pallet<T>>::block_number();
+			let deposit = DIDDeposits::<T>::take(&did_uri);
 			DIDDocument::<T>::remove(&did_uri);
+			DIDsByAccount::<T>::remove(Self::did_indexed_account(&deposit, &did), &did_uri);
+			Self::release_deposit(deposit);
 
 			let event = Event::DIDDocumentRevoked {
 				did_uri,
@@ -300,51 +1238,28 @@ pub mod pallet {
 		/// # Errors
 		/// * `VerifiableCredentialFingerPrintExists` - Verifiable Credential Finger Print already
 		///   exists
+		/// * `MalformedFingerprint` - Fingerprint is not a multihash of `FingerprintHasher`
 		/// * `InvalidPublicKey` - Public Key is invalid
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
 		#[pallet::weight(T::WeightInfo::create_verifiable_credential())]
 		pub fn create_verifiable_credential(
 			origin: OriginFor<T>,
 			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
 			verifiable_credential_input_metadata: VerifiableCredentialMetadataPayload<
 				T::AccountId,
+				T::BlockNumber,
 				T::PublicKeySize,
+				T::DIDURISize,
 			>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
 
-			ensure!(
-				Self::is_substrate_public_key(
-					verifiable_credential_input_metadata.public_key.clone()
-				),
-				Error::<T>::InvalidPublicKey
-			);
-
-			ensure!(
-				!VerifiableCredential::<T>::contains_key(&vc_fingerprint),
-				Error::<T>::VerifiableCredentialFingerPrintExists
-			);
-
-			let block_number = <frame_system::Pallet<T>>::block_number();
-
-			let verifiable_credential_metadata = VerifiableCredentialMetadata {
-				account_id: verifiable_credential_input_metadata.account_id,
-				public_key: verifiable_credential_input_metadata.public_key,
-				block_number,
-				updated_block_number: block_number,
-				active: Some(true),
-			};
-
-			VerifiableCredential::<T>::insert(
-				vc_fingerprint.clone(),
-				verifiable_credential_metadata,
-			);
-			let event = Event::VerifiableCredentialFingerPrintCreated {
+			Self::do_create_verifiable_credential(
+				who,
 				vc_fingerprint,
-				account_id: who,
-				created_block_number: block_number,
-			};
-
-			Self::deposit_event(event);
+				verifiable_credential_input_metadata,
+			)?;
 
 			Ok(().into())
 		}
@@ -355,27 +1270,39 @@ pub mod pallet {
 		/// # Errors
 		/// * `VerifiableCredentialFingerPrintDoesNotExist` - Verifiable Credential Finger Print
 		///   does not exist
-		/// * `InvalidPublicKey` - Public Key is invalid
+		/// * `NotVerifiableCredentialIssuer` - Caller is neither the issuer nor the controller
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
 		#[pallet::weight(T::WeightInfo::revoke_verifiable_credential())]
 		pub fn revoke_verifiable_credential(
 			origin: OriginFor<T>,
 			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
 
-			ensure!(
-				VerifiableCredential::<T>::contains_key(&vc_fingerprint),
-				Error::<T>::VerifiableCredentialFingerPrintDoesNotExist
-			);
+			let verifiable_credential_metadata = VerifiableCredential::<T>::get(&vc_fingerprint)
+				.ok_or(Error::<T>::VerifiableCredentialFingerPrintDoesNotExist)?;
+			Self::ensure_credential_owner(&who, &verifiable_credential_metadata)?;
 
 			let block_number = <frame_system::Pallet<T>>::block_number();
 
 			VerifiableCredential::<T>::remove(&vc_fingerprint);
+			Self::release_deposit(CredentialDeposits::<T>::take(&vc_fingerprint));
+			Self::unindex_credential(&vc_fingerprint, &verifiable_credential_metadata);
+			RevokedVerifiableCredential::<T>::insert(
+				&vc_fingerprint,
+				VerifiableCredentialRevocation {
+					issuer: verifiable_credential_metadata.issuer.clone(),
+					revoked_by: who.clone(),
+					revoked_block_number: block_number,
+				},
+			);
 
 			let event = Event::VerifiableCredentialFingerPrintRevoked {
 				vc_fingerprint,
 				account_id: who,
 				revoked_block_number: block_number,
+				issuer: verifiable_credential_metadata.issuer,
 			};
 
 			Self::deposit_event(event);
@@ -389,124 +1316,2331 @@ pub mod pallet {
 		/// # Errors
 		/// * `VerifiableCredentialFingerPrintDoesNotExist` - Verifiable Credential Finger Print
 		///   does not exist
+		/// * `NotVerifiableCredentialIssuer` - Caller is neither the issuer nor the controller, or
+		///   is the controller and changes `active` or `expiry_block_number`
 		/// * `InvalidPublicKey` - Public Key is invalid
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
 		#[pallet::weight(T::WeightInfo::update_verifiable_credential())]
 		pub fn update_verifiable_credential(
 			origin: OriginFor<T>,
 			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
 			verifiable_credential_input_metadata: VerifiableCredentialMetadataPayload<
 				T::AccountId,
+				T::BlockNumber,
 				T::PublicKeySize,
+				T::DIDURISize,
 			>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
 
-			VerifiableCredential::<T>::mutate(vc_fingerprint.clone(), |vc| match vc {
-				| None => Err(Error::<T>::VerifiableCredentialFingerPrintDoesNotExist),
-				| Some(vc) => {
-					let block_number = <frame_system::Pallet<T>>::block_number();
-					let verifiable_credential_metadata = VerifiableCredentialMetadata {
-						account_id: verifiable_credential_input_metadata.account_id,
-						public_key: verifiable_credential_input_metadata.public_key,
-						block_number: vc.block_number,
-						updated_block_number: block_number,
-						active: verifiable_credential_input_metadata.active,
-					};
-
-					*vc = verifiable_credential_metadata;
-					let event = Event::VerifiableCredentialFingerPrintUpdated {
-						vc_fingerprint,
-						account_id: who,
-						updated_block_number: block_number,
-					};
-					Self::deposit_event(event);
-					Ok(())
-				},
+			ensure!(
+				Self::is_substrate_public_key(
+					verifiable_credential_input_metadata.public_key.clone()
+				),
+				Error::<T>::InvalidPublicKey
+			);
+			Self::ensure_credential_dids_exist(&verifiable_credential_input_metadata)?;
+
+			VerifiableCredential::<T>::mutate(vc_fingerprint.clone(), |vc| -> DispatchResult {
+				let vc =
+					vc.as_mut().ok_or(Error::<T>::VerifiableCredentialFingerPrintDoesNotExist)?;
+				Self::ensure_credential_owner(&who, vc)?;
+				// Only the issuer decides whether the verifiable credential is valid
+				ensure!(
+					vc.issuer == who ||
+						(verifiable_credential_input_metadata.active == vc.active &&
+							verifiable_credential_input_metadata.expiry_block_number ==
+								vc.expiry_block_number),
+					Error::<T>::NotVerifiableCredentialIssuer
+				);
+				let block_number = <frame_system::Pallet<T>>::block_number();
+				let verifiable_credential_metadata = VerifiableCredentialMetadata {
+					account_id: verifiable_credential_input_metadata.account_id,
+					public_key: verifiable_credential_input_metadata.public_key,
+					block_number: vc.block_number,
+					updated_block_number: block_number,
+					timestamp: vc.timestamp,
+					updated_timestamp: Self::now(),
+					active: verifiable_credential_input_metadata.active,
+					issuer: vc.issuer.clone(),
+					expiry_block_number: verifiable_credential_input_metadata.expiry_block_number,
+					issuer_did: verifiable_credential_input_metadata.issuer_did,
+					subject_did: verifiable_credential_input_metadata.subject_did,
+				};
+
+				// The deposit stays with the account that reserved it
+				let previous = CredentialDeposits::<T>::get(&vc_fingerprint);
+				let depositor = previous
+					.as_ref()
+					.map_or_else(|| who.clone(), |deposit| deposit.depositor.clone());
+				let deposit = Self::update_deposit(
+					&depositor,
+					previous,
+					vc_fingerprint.len() + verifiable_credential_metadata.encoded_size(),
+				)?;
+				CredentialDeposits::<T>::insert(&vc_fingerprint, deposit);
+				Self::unindex_credential(&vc_fingerprint, vc);
+				Self::index_credential(&vc_fingerprint, &verifiable_credential_metadata);
+				let event = Event::VerifiableCredentialFingerPrintUpdated {
+					vc_fingerprint,
+					account_id: who,
+					updated_block_number: block_number,
+					issuer: vc.issuer.clone(),
+					metadata_hash: T::Hashing::hash_of(&verifiable_credential_metadata),
+					previous_active: vc.active,
+					active: verifiable_credential_metadata.active,
+				};
+				*vc = verifiable_credential_metadata;
+				Self::deposit_event(event);
+				Ok(())
 			})?;
 
 			Ok(().into())
 		}
 
-		/// Trace credential
+		/// Delete Verifiable Credential
+		/// Purges the metadata and the trail of a revoked or expired credential, leaving only a
+		/// hash tombstone so the fingerprint cannot be anchored again. Each call purges at most
+		/// `max_entries` trail entries and archived trail segments, oldest entries first. The call
+		/// purging the last of them deletes the credential, earlier calls emit
+		/// `VerifiableCredentialTrailPurged`. Deleting also removes the split, merge and migration
+		/// links of the credential, from both ends.
 		/// # Arguments
-		/// * `account_id` - Account Id
 		/// * `vc_fingerprint` - Verifiable Credential Finger Print
-		/// * `status` - Verifiable Credential Status
+		/// * `max_entries` - Maximum number of trail entries and archived segments to purge
 		/// # Errors
 		/// * `VerifiableCredentialFingerPrintDoesNotExist` - Verifiable Credential Finger Print
-		#[pallet::weight(T::WeightInfo::trace_credential())]
-		pub fn trace_credential(
+		///   does not exist
+		/// * `NotVerifiableCredentialIssuer` - Caller is not the issuer
+		/// * `VerifiableCredentialNotRevokedOrExpired` - Verifiable Credential is still valid
+		/// * `TrailMigrationPending` - Trail is not migrated yet
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
+		#[pallet::weight(T::WeightInfo::delete_verifiable_credential(
+			max_entries.saturating_add(T::MaxLineageSize::get().saturating_mul(2))
+		))]
+		pub fn delete_verifiable_credential(
 			origin: OriginFor<T>,
-			account_id: Option<T::AccountId>,
 			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
-			status: VerifiableCredentialStatus,
+			max_entries: u32,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
+			let block_number = <frame_system::Pallet<T>>::block_number();
 
+			let issuer = match RevokedVerifiableCredential::<T>::get(&vc_fingerprint) {
+				Some(revocation) => revocation.issuer,
+				None => {
+					let verifiable_credential_metadata =
+						VerifiableCredential::<T>::get(&vc_fingerprint)
+							.ok_or(Error::<T>::VerifiableCredentialFingerPrintDoesNotExist)?;
+					ensure!(
+						verifiable_credential_metadata
+							.expiry_block_number
+							.map_or(false, |expiry| expiry <= block_number),
+						Error::<T>::VerifiableCredentialNotRevokedOrExpired
+					);
+					verifiable_credential_metadata.issuer
+				},
+			};
+			ensure!(issuer == who, Error::<T>::NotVerifiableCredentialIssuer);
 			ensure!(
-				VerifiableCredential::<T>::contains_key(&vc_fingerprint),
-				Error::<T>::VerifiableCredentialFingerPrintDoesNotExist
+				!migrations::v2::is_trail_pending::<T>(&vc_fingerprint),
+				Error::<T>::TrailMigrationPending
 			);
 
-			let verifiable_credential_metadata = VerifiableCredential::<T>::get(&vc_fingerprint)
-				.ok_or(Error::<T>::VerifiableCredentialFingerPrintDoesNotExist)?;
+			let (purged_trail_entries, purged_segments) =
+				Self::purge_trail(&vc_fingerprint, max_entries);
+			let actual_weight = T::WeightInfo::delete_verifiable_credential(
+				purged_trail_entries.saturating_add(purged_segments),
+			);
+
+			let remaining_trail_entries = VerifiableCredentialTrailHead::<T>::get(&vc_fingerprint)
+				.saturating_sub(VerifiableCredentialTrailTail::<T>::get(&vc_fingerprint));
+			if remaining_trail_entries > 0 ||
+				ArchivedTrailSegmentCount::<T>::get(&vc_fingerprint) > 0
+			{
+				let event = Event::VerifiableCredentialTrailPurged {
+					vc_fingerprint,
+					account_id: who,
+					purged_trail_entries,
+					remaining_trail_entries,
+					block_number,
+				};
+				Self::deposit_event(event);
+				return Ok(Some(actual_weight).into())
+			}
+
+			VerifiableCredentialTrailHead::<T>::remove(&vc_fingerprint);
+			VerifiableCredentialTrailTail::<T>::remove(&vc_fingerprint);
+			ArchivedTrailSegmentCount::<T>::remove(&vc_fingerprint);
+			VerifiableCredentialCurrentStatus::<T>::remove(&vc_fingerprint);
+			let removed_links = Self::remove_lineage(&vc_fingerprint);
+			let actual_weight = T::WeightInfo::delete_verifiable_credential(
+				purged_trail_entries
+					.saturating_add(purged_segments)
+					.saturating_add(removed_links),
+			);
+			if let Some(verifiable_credential_metadata) =
+				VerifiableCredential::<T>::take(&vc_fingerprint)
+			{
+				Self::unindex_credential(&vc_fingerprint, &verifiable_credential_metadata);
+			}
+			Self::release_deposit(CredentialDeposits::<T>::take(&vc_fingerprint));
+			RevokedVerifiableCredential::<T>::remove(&vc_fingerprint);
+			VerifiableCredentialTombstone::<T>::insert(
+				Self::tombstone_key(&vc_fingerprint),
+				block_number,
+			);
+
+			let event = Event::VerifiableCredentialDeleted {
+				vc_fingerprint,
+				account_id: who,
+				purged_trail_entries,
+				deleted_block_number: block_number,
+			};
+
+			Self::deposit_event(event);
+			Ok(Some(actual_weight).into())
+		}
+
+		/// Anchor Credential Batch
+		/// # Arguments
+		/// * `merkle_root` - Merkle root of the hashed Verifiable Credential Finger Prints
+		/// * `batch_size` - Number of Verifiable Credentials in the batch
+		/// # Errors
+		/// * `EmptyCredentialBatch` - Batch size is zero
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
+		#[pallet::weight(T::WeightInfo::anchor_credential_batch())]
+		pub fn anchor_credential_batch(
+			origin: OriginFor<T>,
+			merkle_root: T::Hash,
+			batch_size: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
+
+			ensure!(batch_size > 0, Error::<T>::EmptyCredentialBatch);
+
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			let batch_id = CredentialBatchCount::<T>::mutate(|count| {
+				let batch_id = *count;
+				*count = count.saturating_add(1);
+				batch_id
+			});
+
+			CredentialBatch::<T>::insert(
+				batch_id,
+				CredentialBatchMetadata {
+					merkle_root,
+					issuer: who.clone(),
+					block_number,
+					batch_size,
+					revoked_block_number: None,
+				},
+			);
+
+			let event = Event::CredentialBatchAnchored {
+				batch_id,
+				merkle_root,
+				batch_size,
+				account_id: who,
+				created_block_number: block_number,
+			};
+
+			Self::deposit_event(event);
+			Ok(().into())
+		}
+
+		/// Revoke Credential Batch
+		/// # Arguments
+		/// * `batch_id` - Credential Batch Id
+		/// # Errors
+		/// * `CredentialBatchDoesNotExist` - Credential Batch does not exist
+		/// * `NotCredentialBatchIssuer` - Caller is not the issuer
+		/// * `CredentialBatchRevoked` - Credential Batch is already revoked
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
+		#[pallet::weight(T::WeightInfo::revoke_credential_batch())]
+		pub fn revoke_credential_batch(
+			origin: OriginFor<T>,
+			batch_id: u64,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
+			let block_number = <frame_system::Pallet<T>>::block_number();
+
+			CredentialBatch::<T>::try_mutate(batch_id, |batch| -> DispatchResult {
+				let batch = batch.as_mut().ok_or(Error::<T>::CredentialBatchDoesNotExist)?;
+				ensure!(batch.issuer == who, Error::<T>::NotCredentialBatchIssuer);
+				ensure!(batch.revoked_block_number.is_none(), Error::<T>::CredentialBatchRevoked);
+				batch.revoked_block_number = Some(block_number);
+				Ok(())
+			})?;
+
+			let event = Event::CredentialBatchRevoked {
+				batch_id,
+				account_id: who,
+				revoked_block_number: block_number,
+			};
+
+			Self::deposit_event(event);
+			Ok(().into())
+		}
+
+		/// Revoke a single Verifiable Credential of a Credential Batch
+		/// # Arguments
+		/// * `batch_id` - Credential Batch Id
+		/// * `vc_fingerprint` - Verifiable Credential Finger Print
+		/// # Errors
+		/// * `CredentialBatchDoesNotExist` - Credential Batch does not exist
+		/// * `NotCredentialBatchIssuer` - Caller is not the issuer
+		/// * `CredentialBatchRevoked` - Credential Batch or the credential is already revoked
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
+		#[pallet::weight(T::WeightInfo::revoke_batch_credential())]
+		pub fn revoke_batch_credential(
+			origin: OriginFor<T>,
+			batch_id: u64,
+			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
+
+			let batch = CredentialBatch::<T>::get(batch_id)
+				.ok_or(Error::<T>::CredentialBatchDoesNotExist)?;
+			ensure!(batch.issuer == who, Error::<T>::NotCredentialBatchIssuer);
+			ensure!(batch.revoked_block_number.is_none(), Error::<T>::CredentialBatchRevoked);
+
+			let leaf = Self::batch_leaf(&vc_fingerprint);
 			ensure!(
-				verifiable_credential_metadata.active == Some(true),
-				Error::<T>::VerifiableCredentialInactive
+				!RevokedBatchCredential::<T>::contains_key(batch_id, leaf),
+				Error::<T>::CredentialBatchRevoked
 			);
 
 			let block_number = <frame_system::Pallet<T>>::block_number();
-			let mut vc_log =
-				VerifiableCredentialLog { account_id, block_number: Some(block_number), status };
+			RevokedBatchCredential::<T>::insert(batch_id, leaf, block_number);
 
-			match VerifiableCredentialTrail::<T>::get(&vc_fingerprint) {
-				None => {
-					let mut arr: BoundedVec<
-						VerifiableCredentialLog<T::AccountId, T::BlockNumber>,
-						T::MetadataSize,
-					> = Default::default();
-					vc_log.status = VerifiableCredentialStatus::Created;
-					arr.try_push(vc_log)
-						.map_err(|_| Error::<T>::VerifiableCredentialLogLimitReached)?;
-					VerifiableCredentialTrail::<T>::insert(&vc_fingerprint, arr);
-					let event = Event::VerifiableCredentialEvent {
-						vc_fingerprint,
-						origin: who,
-						block_number,
-						status: VerifiableCredentialStatus::Created,
-					};
-					Self::deposit_event(event);
-				},
-				Some(_) => {
-					VerifiableCredentialTrail::<T>::try_mutate(
-						vc_fingerprint.clone(),
-						|x| -> DispatchResult {
-							let x = x
-								.as_mut()
-								.ok_or(Error::<T>::VerifiableCredentialFingerPrintDoesNotExist)?;
-							x.try_push(vc_log.clone())
-								.map_err(|_| Error::<T>::VerifiableCredentialLogLimitReached)?;
-							let event = Event::VerifiableCredentialEvent {
-								vc_fingerprint,
-								origin: who,
-								block_number,
-								status: vc_log.status,
-							};
-							Self::deposit_event(event);
-							Ok(())
-						},
-					)?;
-				},
-			}
+			let event = Event::BatchCredentialRevoked {
+				batch_id,
+				vc_fingerprint,
+				account_id: who,
+				revoked_block_number: block_number,
+			};
+
+			Self::deposit_event(event);
 			Ok(().into())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		// TODO: implement for other supported crypto types pks
-		/// Increment the cluster id generator and return the id
-		fn is_substrate_public_key(public_key: BoundedVec<u8, T::PublicKeySize>) -> bool {
-			T::AccountId::decode(&mut &public_key.to_vec()[..]).is_ok()
+		/// Split Verifiable Credential
+		/// Creates child credentials linked to the parent and records `Split` on the parent trail
+		/// # Arguments
+		/// * `vc_fingerprint` - Parent Verifiable Credential Finger Print
+		/// * `children` - Child Verifiable Credential Finger Prints
+		/// # Errors
+		/// * `InvalidCredentialLineage` - Children are empty or repeat a fingerprint
+		/// * `NotVerifiableCredentialIssuer` - Caller is not the issuer of the parent
+		/// * `VerifiableCredentialFingerPrintExists` - Child Finger Print already exists
+		/// * `MalformedFingerprint` - Child Finger Print is not a multihash of `FingerprintHasher`
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
+		#[pallet::weight(T::WeightInfo::split_credential(children.len() as u32))]
+		pub fn split_credential(
+			origin: OriginFor<T>,
+			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
+			children: BoundedVec<BoundedVec<u8, T::VCFingerPrintSize>, T::MaxLineageSize>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
+
+			ensure!(!children.is_empty(), Error::<T>::InvalidCredentialLineage);
+			let parent = Self::ensure_lineage_parent(
+				&who,
+				&vc_fingerprint,
+				&VerifiableCredentialStatus::Split,
+			)?;
+			for (index, child) in children.iter().enumerate() {
+				ensure!(
+					*child != vc_fingerprint && !children[..index].contains(child),
+					Error::<T>::InvalidCredentialLineage
+				);
+				Self::ensure_new_fingerprint(child)?;
+			}
+
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			Self::close_lineage_parent(&who, &vc_fingerprint, VerifiableCredentialStatus::Split)?;
+			for child in children.iter() {
+				Self::insert_lineage_child(&who, child, &parent)?;
+				CredentialParents::<T>::insert(child, &vc_fingerprint, ());
+				CredentialChildren::<T>::insert(&vc_fingerprint, child, ());
+			}
+
+			let event = Event::VerifiableCredentialSplit {
+				vc_fingerprint,
+				children,
+				account_id: who,
+				block_number,
+			};
+
+			Self::deposit_event(event);
+			Ok(().into())
+		}
+
+		/// Merge Verifiable Credentials
+		/// Creates one child credential linked to all parents and records `Merged` on the parent
+		/// trails
+		/// # Arguments
+		/// * `parents` - Parent Verifiable Credential Finger Prints
+		/// * `vc_fingerprint` - Child Verifiable Credential Finger Print
+		/// # Errors
+		/// * `InvalidCredentialLineage` - Parents are empty or repeat a fingerprint
+		/// * `NotVerifiableCredentialIssuer` - Caller is not the issuer of a parent
+		/// * `VerifiableCredentialFingerPrintExists` - Child Finger Print already exists
+		/// * `MalformedFingerprint` - Child Finger Print is not a multihash of `FingerprintHasher`
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
+		#[pallet::weight(T::WeightInfo::merge_credentials(parents.len() as u32))]
+		pub fn merge_credentials(
+			origin: OriginFor<T>,
+			parents: BoundedVec<BoundedVec<u8, T::VCFingerPrintSize>, T::MaxLineageSize>,
+			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
+
+			let mut first_parent = None;
+			for (index, parent) in parents.iter().enumerate() {
+				ensure!(
+					*parent != vc_fingerprint && !parents[..index].contains(parent),
+					Error::<T>::InvalidCredentialLineage
+				);
+				let metadata =
+					Self::ensure_lineage_parent(&who, parent, &VerifiableCredentialStatus::Merged)?;
+				first_parent.get_or_insert(metadata);
+			}
+			let first_parent = first_parent.ok_or(Error::<T>::InvalidCredentialLineage)?;
+			Self::ensure_new_fingerprint(&vc_fingerprint)?;
+
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			for parent in parents.iter() {
+				Self::close_lineage_parent(&who, parent, VerifiableCredentialStatus::Merged)?;
+				CredentialParents::<T>::insert(&vc_fingerprint, parent, ());
+				CredentialChildren::<T>::insert(parent, &vc_fingerprint, ());
+			}
+			Self::insert_lineage_child(&who, &vc_fingerprint, &first_parent)?;
+
+			let event = Event::VerifiableCredentialsMerged {
+				parents,
+				vc_fingerprint,
+				account_id: who,
+				block_number,
+			};
+
+			Self::deposit_event(event);
+			Ok(().into())
+		}
+
+		/// Migrate Verifiable Credential
+		/// Anchors the credential under a new fingerprint, marks the old one as `Migrated` and
+		/// links both so the combined history can be followed as one chain
+		/// # Arguments
+		/// * `vc_fingerprint` - Verifiable Credential Finger Print
+		/// * `new_vc_fingerprint` - New Verifiable Credential Finger Print
+		/// # Errors
+		/// * `VerifiableCredentialFingerPrintDoesNotExist` - Verifiable Credential Finger Print
+		///   does not exist
+		/// * `NotVerifiableCredentialIssuer` - Caller is not the issuer
+		/// * `VerifiableCredentialFingerPrintExists` - New Finger Print already exists
+		/// * `MalformedFingerprint` - New Finger Print is not a multihash of `FingerprintHasher`
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
+		#[pallet::weight(T::WeightInfo::migrate_credential())]
+		pub fn migrate_credential(
+			origin: OriginFor<T>,
+			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
+			new_vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
+
+			let verifiable_credential_metadata = Self::ensure_lineage_parent(
+				&who,
+				&vc_fingerprint,
+				&VerifiableCredentialStatus::Migrated,
+			)?;
+			Self::ensure_new_fingerprint(&new_vc_fingerprint)?;
+
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			Self::close_lineage_parent(
+				&who,
+				&vc_fingerprint,
+				VerifiableCredentialStatus::Migrated,
+			)?;
+			VerifiableCredential::<T>::mutate(&vc_fingerprint, |vc| {
+				if let Some(vc) = vc {
+					vc.active = Some(false);
+					vc.updated_block_number = block_number;
+				}
+			});
+			Self::insert_lineage_child(&who, &new_vc_fingerprint, &verifiable_credential_metadata)?;
+			CredentialMigratedTo::<T>::insert(&vc_fingerprint, &new_vc_fingerprint);
+			CredentialMigratedFrom::<T>::insert(&new_vc_fingerprint, &vc_fingerprint);
+
+			let event = Event::VerifiableCredentialMigrated {
+				vc_fingerprint,
+				new_vc_fingerprint,
+				account_id: who,
+				block_number,
+			};
+
+			Self::deposit_event(event);
+			Ok(().into())
+		}
+
+		/// Trace credential
+		/// # Arguments
+		/// * `account_id` - Account Id
+		/// * `vc_fingerprint` - Verifiable Credential Finger Print
+		/// * `status` - Verifiable Credential Status
+		/// * `metadata` - Location code, sensor reading or other bounded metadata
+		/// * `evidence_hash` - Hash of the evidence backing the log entry
+		/// # Errors
+		/// * `VerifiableCredentialFingerPrintDoesNotExist` - Verifiable Credential Finger Print
+		/// * `InvalidInitialStatus` - First status is neither `Pending` nor `Created`
+		/// * `VerifiableCredentialFrozen` - Verifiable Credential is frozen
+		/// * `VerifiableCredentialTerminated` - Verifiable Credential was migrated, split or merged
+		/// * `LineageStatusReserved` - Status is `Migrated`, `Split` or `Merged`
+		/// * `ResumeWithoutSuspend` - `Resumed` does not follow `Suspended`
+		/// * `InvalidStatusTransition` - Status may not follow the current status
+		/// * `RoleNotAssigned` - Caller holds no role of an issuer with roles
+		/// * `RoleStatusNotAllowed` - Role of the caller may not append the status
+		/// * `IssuerRateLimited` - Caller exceeded `MaxIssuerTracesPerWindow` issuer trail events
+		/// * `TrailMigrationPending` - Trail is not migrated yet
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
+		#[pallet::weight(T::WeightInfo::trace_credential())]
+		pub fn trace_credential(
+			origin: OriginFor<T>,
+			account_id: Option<T::AccountId>,
+			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
+			status: VerifiableCredentialStatus,
+			metadata: Option<BoundedVec<u8, T::TrailMetadataSize>>,
+			evidence_hash: Option<T::Hash>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
+
+			Self::do_trace_credential(
+				who,
+				account_id,
+				None,
+				vc_fingerprint,
+				status,
+				metadata,
+				evidence_hash,
+			)?;
+			Ok(().into())
+		}
+
+		/// Archive the oldest trail entries of a verifiable credential. Entries older than
+		/// `TrailArchiveAge` are pruned from state and replaced with the merkle root and count of
+		/// the segment. The pruned entries are kept in the offchain indexed database.
+		/// # Arguments
+		/// * `vc_fingerprint` - Verifiable Credential Finger Print
+		/// # Errors
+		/// * `NothingToArchive` - No trail entry is old enough to be archived
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
+		#[pallet::weight(T::WeightInfo::archive_verifiable_credential_trail(
+			T::MaxArchiveEntries::get()
+		))]
+		pub fn archive_verifiable_credential_trail(
+			origin: OriginFor<T>,
+			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
+			let block_number = <frame_system::Pallet<T>>::block_number();
+
+			let start = VerifiableCredentialTrailTail::<T>::get(&vc_fingerprint);
+			let head = VerifiableCredentialTrailHead::<T>::get(&vc_fingerprint);
+			let mut entries = Vec::new();
+			for sequence in start..head.min(start.saturating_add(T::MaxArchiveEntries::get())) {
+				match VerifiableCredentialTrail::<T>::get(&vc_fingerprint, sequence) {
+					| Some(vc_log)
+						if vc_log.block_number.map_or(false, |logged| {
+							logged.saturating_add(T::TrailArchiveAge::get()) <= block_number
+						}) =>
+						entries.push(vc_log),
+					| _ => break,
+				}
+			}
+			ensure!(!entries.is_empty(), Error::<T>::NothingToArchive);
+
+			let count = entries.len() as u32;
+			let leaves: Vec<T::Hash> = entries
+				.iter()
+				.zip(start..)
+				.map(|(vc_log, sequence)| Self::trail_leaf(sequence, vc_log))
+				.collect();
+			let merkle_root = Self::build_merkle_root(leaves);
+
+			for sequence in start..start + count {
+				VerifiableCredentialTrail::<T>::remove(&vc_fingerprint, sequence);
+				Self::release_deposit(TrailDeposits::<T>::take(&vc_fingerprint, sequence));
+			}
+			VerifiableCredentialTrailTail::<T>::insert(&vc_fingerprint, start + count);
+
+			let segment = ArchivedTrailSegmentCount::<T>::mutate(&vc_fingerprint, |segments| {
+				let segment = *segments;
+				*segments = segments.saturating_add(1);
+				segment
+			});
+			ArchivedTrailSegments::<T>::insert(
+				&vc_fingerprint,
+				segment,
+				ArchivedTrailSegment {
+					merkle_root,
+					start,
+					count,
+					archived_block_number: block_number,
+				},
+			);
+			sp_io::offchain_index::set(
+				&Self::trail_archive_key(&vc_fingerprint, segment),
+				&(start, entries).encode(),
+			);
+
+			let event = Event::VerifiableCredentialTrailArchived {
+				vc_fingerprint,
+				segment,
+				merkle_root,
+				start,
+				count,
+				block_number,
+			};
+			Self::deposit_event(event);
+
+			Ok(().into())
+		}
+
+		/// Register a custom status in the vocabulary of the caller. Trail entries of the
+		/// verifiable credentials issued by the caller may use it as `Custom(status_id)`.
+		/// # Arguments
+		/// * `status_id` - Custom status id
+		/// * `label` - Custom status label
+		/// # Errors
+		/// * `CustomStatusExists` - Custom status is already registered
+		/// * `TooManyCustomStatuses` - Caller registered `MaxCustomStatuses` custom statuses
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
+		#[pallet::weight(T::WeightInfo::register_custom_status())]
+		pub fn register_custom_status(
+			origin: OriginFor<T>,
+			status_id: u16,
+			label: BoundedVec<u8, T::CustomStatusLabelSize>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
+
+			ensure!(
+				!CustomStatusVocabulary::<T>::contains_key(&who, status_id),
+				Error::<T>::CustomStatusExists
+			);
+			CustomStatusCount::<T>::try_mutate(&who, |count| {
+				ensure!(*count < T::MaxCustomStatuses::get(), Error::<T>::TooManyCustomStatuses);
+				*count += 1;
+				Ok::<(), Error<T>>(())
+			})?;
+			CustomStatusVocabulary::<T>::insert(&who, status_id, label.clone());
+
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			let event =
+				Event::CustomStatusRegistered { account_id: who, status_id, label, block_number };
+			Self::deposit_event(event);
+
+			Ok(().into())
+		}
+
+		/// Remove a custom status from the vocabulary of the caller. Existing trail entries keep
+		/// referencing it.
+		/// # Arguments
+		/// * `status_id` - Custom status id
+		/// # Errors
+		/// * `CustomStatusNotRegistered` - Custom status is not registered
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
+		#[pallet::weight(T::WeightInfo::remove_custom_status())]
+		pub fn remove_custom_status(
+			origin: OriginFor<T>,
+			status_id: u16,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
+
+			ensure!(
+				CustomStatusVocabulary::<T>::contains_key(&who, status_id),
+				Error::<T>::CustomStatusNotRegistered
+			);
+			CustomStatusVocabulary::<T>::remove(&who, status_id);
+			CustomStatusCount::<T>::mutate(&who, |count| *count = count.saturating_sub(1));
+
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			let event = Event::CustomStatusRemoved { account_id: who, status_id, block_number };
+			Self::deposit_event(event);
+
+			Ok(().into())
+		}
+
+		/// Register a role that may append the given statuses to the trails of the caller's
+		/// verifiable credentials. Once an issuer registers a role, only accounts and DIDs assigned
+		/// to a role allowing the status may trace its verifiable credentials.
+		/// # Arguments
+		/// * `role_id` - Role id
+		/// * `name` - Role name such as manufacturer, carrier, customs or retailer
+		/// * `allowed_statuses` - Statuses members of the role may append to a trail
+		/// # Errors
+		/// * `RoleExists` - Role is already registered
+		/// * `TooManyRoles` - Caller registered `MaxRoles` roles
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
+		#[pallet::weight(T::WeightInfo::register_role())]
+		pub fn register_role(
+			origin: OriginFor<T>,
+			role_id: u16,
+			name: BoundedVec<u8, T::RoleNameSize>,
+			allowed_statuses: BoundedVec<VerifiableCredentialStatus, T::MaxRoleStatuses>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
+
+			ensure!(!Roles::<T>::contains_key(&who, role_id), Error::<T>::RoleExists);
+			RoleCount::<T>::try_mutate(&who, |count| {
+				ensure!(*count < T::MaxRoles::get(), Error::<T>::TooManyRoles);
+				*count += 1;
+				Ok::<(), Error<T>>(())
+			})?;
+
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			let role = Role { name: name.clone(), allowed_statuses, members: 0, block_number };
+			Roles::<T>::insert(&who, role_id, role);
+
+			let event = Event::RoleRegistered { account_id: who, role_id, name, block_number };
+			Self::deposit_event(event);
+
+			Ok(().into())
+		}
+
+		/// Remove a role without assigned accounts or DIDs
+		/// # Arguments
+		/// * `role_id` - Role id
+		/// # Errors
+		/// * `RoleDoesNotExist` - Role is not registered
+		/// * `RoleHasMembers` - Role still has assigned accounts or DIDs
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
+		#[pallet::weight(T::WeightInfo::remove_role())]
+		pub fn remove_role(origin: OriginFor<T>, role_id: u16) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
+
+			let role = Roles::<T>::get(&who, role_id).ok_or(Error::<T>::RoleDoesNotExist)?;
+			ensure!(role.members == 0, Error::<T>::RoleHasMembers);
+			Roles::<T>::remove(&who, role_id);
+			RoleCount::<T>::mutate(&who, |count| *count = count.saturating_sub(1));
+
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			let event = Event::RoleRemoved { account_id: who, role_id, block_number };
+			Self::deposit_event(event);
+
+			Ok(().into())
+		}
+
+		/// Assign an account or DID to a role of the caller, replacing its previous role
+		/// # Arguments
+		/// * `member` - Account or DID
+		/// * `role_id` - Role id
+		/// # Errors
+		/// * `RoleDoesNotExist` - Role is not registered
+		/// * `DIDDoesNotExist` - DID Document not exists
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
+		#[pallet::weight(T::WeightInfo::assign_role())]
+		pub fn assign_role(
+			origin: OriginFor<T>,
+			member: RoleMember<T::AccountId, T::DIDURISize>,
+			role_id: u16,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
+
+			ensure!(Roles::<T>::contains_key(&who, role_id), Error::<T>::RoleDoesNotExist);
+			if let RoleMember::DID(did_uri) = &member {
+				ensure!(DIDDocument::<T>::contains_key(did_uri), Error::<T>::DIDDoesNotExist);
+			}
+
+			if let Some(previous_role_id) = RoleAssignments::<T>::get(&who, &member) {
+				Roles::<T>::mutate(&who, previous_role_id, |role| {
+					if let Some(role) = role {
+						role.members = role.members.saturating_sub(1);
+					}
+				});
+			}
+			Roles::<T>::mutate(&who, role_id, |role| {
+				if let Some(role) = role {
+					role.members = role.members.saturating_add(1);
+				}
+			});
+			RoleAssignments::<T>::insert(&who, &member, role_id);
+
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			let event = Event::RoleAssigned { account_id: who, member, role_id, block_number };
+			Self::deposit_event(event);
+
+			Ok(().into())
+		}
+
+		/// Unassign an account or DID from its role of the caller
+		/// # Arguments
+		/// * `member` - Account or DID
+		/// # Errors
+		/// * `RoleNotAssigned` - Account or DID is not assigned to a role of the caller
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
+		#[pallet::weight(T::WeightInfo::unassign_role())]
+		pub fn unassign_role(
+			origin: OriginFor<T>,
+			member: RoleMember<T::AccountId, T::DIDURISize>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
+
+			let role_id =
+				RoleAssignments::<T>::take(&who, &member).ok_or(Error::<T>::RoleNotAssigned)?;
+			Roles::<T>::mutate(&who, role_id, |role| {
+				if let Some(role) = role {
+					role.members = role.members.saturating_sub(1);
+				}
+			});
+
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			let event = Event::RoleUnassigned { account_id: who, member, role_id, block_number };
+			Self::deposit_event(event);
+
+			Ok(().into())
+		}
+
+		/// Register a scanning device that signs trail events on behalf of a DID
+		/// # Arguments
+		/// * `did_uri` - DID URI
+		/// * `device_key` - Device ed25519 public key
+		/// * `label` - Device label
+		/// * `allowed_statuses` - Statuses the device may append to a trail
+		/// # Errors
+		/// * `DIDDoesNotExist` - DID Document not exists
+		/// * `NotDIDController` - Caller is not the controller of the DID
+		/// * `DeviceExists` - Device is already registered
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
+		#[pallet::weight(T::WeightInfo::register_device())]
+		pub fn register_device(
+			origin: OriginFor<T>,
+			did_uri: BoundedVec<u8, T::DIDURISize>,
+			device_key: DevicePublicKey,
+			label: BoundedVec<u8, T::DeviceLabelSize>,
+			allowed_statuses: BoundedVec<VerifiableCredentialStatus, T::MaxDeviceStatuses>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
+
+			let did = DIDDocument::<T>::get(&did_uri).ok_or(Error::<T>::DIDDoesNotExist)?;
+			ensure!(did.sender_account_id == who, Error::<T>::NotDIDController);
+			ensure!(!Devices::<T>::contains_key(&device_key), Error::<T>::DeviceExists);
+
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			let device = Device {
+				did_uri: did_uri.clone(),
+				account_id: who.clone(),
+				label,
+				allowed_statuses,
+				block_number,
+			};
+			Devices::<T>::insert(&device_key, device);
+
+			let event =
+				Event::DeviceRegistered { did_uri, device_key, account_id: who, block_number };
+			Self::deposit_event(event);
+
+			Ok(().into())
+		}
+
+		/// Remove a scanning device
+		/// # Arguments
+		/// * `device_key` - Device ed25519 public key
+		/// # Errors
+		/// * `DeviceDoesNotExist` - Device is not registered
+		/// * `NotDeviceController` - Caller is not the controller of the device
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
+		#[pallet::weight(T::WeightInfo::remove_device())]
+		pub fn remove_device(
+			origin: OriginFor<T>,
+			device_key: DevicePublicKey,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
+
+			let device = Devices::<T>::get(&device_key).ok_or(Error::<T>::DeviceDoesNotExist)?;
+			ensure!(device.account_id == who, Error::<T>::NotDeviceController);
+
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			Devices::<T>::remove(&device_key);
+			DeviceTraceCount::<T>::remove(&device_key);
+
+			let event = Event::DeviceRemoved {
+				did_uri: device.did_uri,
+				device_key,
+				account_id: who,
+				block_number,
+			};
+			Self::deposit_event(event);
+
+			Ok(().into())
+		}
+
+		/// Trace credential with a payload signed by a registered device. The signature is
+		/// checked in `validate_unsigned`, so the device needs no funded account.
+		/// # Arguments
+		/// * `payload` - Trail event signed by the device
+		/// * `signature` - Device signature of the encoded payload
+		/// # Errors
+		/// * `DeviceDoesNotExist` - Device is not registered
+		/// * `InvalidDeviceGenesisHash` - Payload was signed for another chain
+		/// * `DeviceStatusNotAllowed` - Device may not append the status to a trail
+		/// * `InvalidDeviceNonce` - Payload nonce does not match the device nonce
+		/// * `DeviceRateLimited` - Device signed too many trail events in the current period
+		/// * `VerifiableCredentialFingerPrintDoesNotExist` - Verifiable Credential Finger Print
+		/// * `VerifiableCredentialInactive` - Verifiable Credential is not active
+		/// * `LineageStatusReserved` - Status is `Migrated`, `Split` or `Merged`
+		/// * `CustomStatusNotRegistered` - Custom status is not in the issuer vocabulary
+		/// * `InvalidStatusTransition` - Status may not follow the current status
+		/// * `RoleNotAssigned` - Neither the device DID nor its controller holds a role
+		/// * `RoleStatusNotAllowed` - Role of the device may not append the status
+		/// * `IssuerRateLimited` - Device exceeded `MaxIssuerTracesPerWindow` issuer trail events
+		/// * `TrailMigrationPending` - Trail is not migrated yet
+		#[pallet::weight(T::WeightInfo::trace_credential_signed())]
+		pub fn trace_credential_signed(
+			origin: OriginFor<T>,
+			payload: DeviceTracePayload<T::Hash, T::VCFingerPrintSize, T::TrailMetadataSize>,
+			_signature: DeviceSignature,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+
+			let device = Self::ensure_device_trace(&payload)?;
+			ensure!(
+				payload.nonce == DeviceNonce::<T>::get(&payload.device_key),
+				Error::<T>::InvalidDeviceNonce
+			);
+
+			Self::do_trace_credential(
+				device.account_id.clone(),
+				Some(device.account_id),
+				Some(&device.did_uri),
+				payload.vc_fingerprint.clone(),
+				payload.status,
+				payload.metadata,
+				payload.evidence_hash,
+			)?;
+
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			DeviceNonce::<T>::insert(&payload.device_key, payload.nonce.saturating_add(1));
+			DeviceTraceCount::<T>::mutate(&payload.device_key, |(period_start, count)| {
+				if block_number >= (*period_start).saturating_add(T::DeviceTracePeriod::get()) {
+					*period_start = block_number;
+					*count = 0;
+				}
+				*count = count.saturating_add(1);
+			});
+
+			let event = Event::DeviceTraceRecorded {
+				vc_fingerprint: payload.vc_fingerprint,
+				device_key: payload.device_key,
+				nonce: payload.nonce,
+				block_number,
+			};
+			Self::deposit_event(event);
+
+			Ok(().into())
+		}
+
+		/// Exempt an account from rate limits or revoke its exemption
+		/// # Arguments
+		/// * `account_id` - Account Id
+		/// * `exempt` - Whether the account is exempt
+		#[pallet::weight(T::WeightInfo::set_rate_limit_exemption())]
+		pub fn set_rate_limit_exemption(
+			origin: OriginFor<T>,
+			account_id: T::AccountId,
+			exempt: bool,
+		) -> DispatchResultWithPostInfo {
+			T::RateLimitOrigin::ensure_origin(origin)?;
+
+			if exempt {
+				RateLimitExempt::<T>::insert(&account_id, ());
+			} else {
+				RateLimitExempt::<T>::remove(&account_id);
+			}
+
+			let event = Event::RateLimitExemptionSet { account_id, exempt };
+			Self::deposit_event(event);
+
+			Ok(().into())
+		}
+
+		/// Anchor Verifiable Credential Content
+		/// Hashes the canonical credential bytes with `FingerprintHasher` and anchors the
+		/// credential if the hash matches the supplied fingerprint
+		/// # Arguments
+		/// * `vc_fingerprint` - Verifiable Credential Finger Print
+		/// * `content` - Canonical credential bytes
+		/// * `verifiable_credential_input_metadata` - Verifiable Credential Input Metadata
+		/// # Errors
+		/// * `MalformedFingerprint` - Fingerprint is not a multihash of `FingerprintHasher`
+		/// * `FingerprintMismatch` - Fingerprint does not match the hash of the content
+		/// * `VerifiableCredentialFingerPrintExists` - Verifiable Credential Finger Print already
+		///   exists
+		/// * `InvalidPublicKey` - Public Key is invalid
+		/// * `AccountRateLimited` - Caller exceeded `MaxOperationsPerWindow` calls
+		#[pallet::weight(T::WeightInfo::anchor_credential_content(content.len() as u32))]
+		pub fn anchor_credential_content(
+			origin: OriginFor<T>,
+			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
+			content: BoundedVec<u8, T::MaxCredentialContentSize>,
+			verifiable_credential_input_metadata: VerifiableCredentialMetadataPayload<
+				T::AccountId,
+				T::BlockNumber,
+				T::PublicKeySize,
+				T::DIDURISize,
+			>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_account_rate_limit(&who)?;
+
+			ensure!(
+				T::FingerprintHasher::is_fingerprint(&vc_fingerprint),
+				Error::<T>::MalformedFingerprint
+			);
+			ensure!(
+				Self::fingerprint_of(&content) == vc_fingerprint.to_vec(),
+				Error::<T>::FingerprintMismatch
+			);
+			Self::do_create_verifiable_credential(
+				who,
+				vc_fingerprint,
+				verifiable_credential_input_metadata,
+			)?;
+
+			Ok(().into())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let (payload, signature) = match call {
+				| Call::trace_credential_signed { payload, signature } => (payload, signature),
+				| _ => return InvalidTransaction::Call.into(),
+			};
+
+			let device_nonce = DeviceNonce::<T>::get(&payload.device_key);
+			if payload.nonce < device_nonce {
+				return InvalidTransaction::Stale.into()
+			}
+
+			let device = Self::ensure_device_trace(payload).map_err(|error| match error {
+				| Error::<T>::DeviceDoesNotExist => InvalidTransaction::BadSigner,
+				| Error::<T>::InvalidDeviceGenesisHash => InvalidTransaction::BadProof,
+				| Error::<T>::DeviceRateLimited => InvalidTransaction::ExhaustsResources,
+				| _ => InvalidTransaction::Call,
+			})?;
+
+			// Run the checks of the dispatch up front, so a payload that would fail cannot be
+			// included without consuming the device nonce
+			let (verifiable_credential_metadata, ..) = Self::ensure_trace_allowed(
+				&device.account_id,
+				Some(&device.did_uri),
+				&payload.vc_fingerprint,
+				&payload.status,
+			)
+			.map_err(|_| InvalidTransaction::Call)?;
+			if !Self::issuer_may_trace(&verifiable_credential_metadata.issuer, &device.account_id) {
+				return InvalidTransaction::ExhaustsResources.into()
+			}
+
+			if !signature.verify(&payload.encode()[..], &payload.device_key) {
+				return InvalidTransaction::BadProof.into()
+			}
+
+			let mut transaction = ValidTransaction::with_tag_prefix("VerifiableDeviceTrace")
+				.priority(T::DeviceTracePriority::get())
+				.and_provides((payload.device_key, payload.nonce))
+				.longevity(T::DeviceTracePeriod::get().saturated_into::<u64>())
+				.propagate(true);
+			if payload.nonce > device_nonce {
+				transaction = transaction.and_requires((payload.device_key, payload.nonce - 1));
+			}
+			transaction.build()
+		}
+
+		fn pre_dispatch(call: &Self::Call) -> Result<(), TransactionValidityError> {
+			// Only the next nonce may be included, a future one would fail the dispatch and fill
+			// the block with a free trace
+			if let Call::trace_credential_signed { payload, .. } = call {
+				let device_nonce = DeviceNonce::<T>::get(&payload.device_key);
+				if payload.nonce < device_nonce {
+					return Err(InvalidTransaction::Stale.into())
+				}
+				if payload.nonce > device_nonce {
+					return Err(InvalidTransaction::Future.into())
+				}
+			}
+			Self::validate_unsigned(TransactionSource::InBlock, call).map(|_| ())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Anchor a verifiable credential issued by `who`
+		fn do_create_verifiable_credential(
+			who: T::AccountId,
+			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
+			verifiable_credential_input_metadata: VerifiableCredentialMetadataPayload<
+				T::AccountId,
+				T::BlockNumber,
+				T::PublicKeySize,
+				T::DIDURISize,
+			>,
+		) -> DispatchResult {
+			ensure!(
+				Self::is_substrate_public_key(
+					verifiable_credential_input_metadata.public_key.clone()
+				),
+				Error::<T>::InvalidPublicKey
+			);
+
+			Self::ensure_new_fingerprint(&vc_fingerprint)?;
+			Self::ensure_credential_dids_exist(&verifiable_credential_input_metadata)?;
+
+			let block_number = <frame_system::Pallet<T>>::block_number();
+
+			let verifiable_credential_metadata = VerifiableCredentialMetadata {
+				account_id: verifiable_credential_input_metadata.account_id,
+				public_key: verifiable_credential_input_metadata.public_key,
+				block_number,
+				updated_block_number: block_number,
+				timestamp: Self::now(),
+				updated_timestamp: Self::now(),
+				active: Some(true),
+				issuer: who.clone(),
+				expiry_block_number: verifiable_credential_input_metadata.expiry_block_number,
+				issuer_did: verifiable_credential_input_metadata.issuer_did,
+				subject_did: verifiable_credential_input_metadata.subject_did,
+			};
+
+			let metadata_hash = T::Hashing::hash_of(&verifiable_credential_metadata);
+			Self::insert_verifiable_credential(
+				&who,
+				&vc_fingerprint,
+				verifiable_credential_metadata,
+			)?;
+			let event = Event::VerifiableCredentialFingerPrintCreated {
+				vc_fingerprint,
+				account_id: who,
+				created_block_number: block_number,
+				metadata_hash,
+			};
+
+			Self::deposit_event(event);
+			Ok(())
+		}
+
+		// TODO: implement for other supported crypto types pks
+		/// Increment the cluster id generator and return the id
+		fn is_substrate_public_key(public_key: BoundedVec<u8, T::PublicKeySize>) -> bool {
+			T::AccountId::decode(&mut &public_key.to_vec()[..]).is_ok()
+		}
+
+		/// Append a status to the trail of an active verifiable credential. `did_uri` is the DID
+		/// of the device signing the trail event, if any.
+		fn do_trace_credential(
+			who: T::AccountId,
+			account_id: Option<T::AccountId>,
+			did_uri: Option<&BoundedVec<u8, T::DIDURISize>>,
+			vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize>,
+			status: VerifiableCredentialStatus,
+			metadata: Option<BoundedVec<u8, T::TrailMetadataSize>>,
+			evidence_hash: Option<T::Hash>,
+		) -> DispatchResult {
+			let (verifiable_credential_metadata, role, current_status) =
+				Self::ensure_trace_allowed(&who, did_uri, &vc_fingerprint, &status)?;
+			Self::ensure_issuer_rate_limit(&verifiable_credential_metadata.issuer, &who)?;
+
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			let vc_log = VerifiableCredentialLog {
+				account_id: account_id.clone(),
+				block_number: Some(block_number),
+				timestamp: Some(Self::now()),
+				status: status.clone(),
+				metadata,
+				evidence_hash,
+				role,
+			};
+
+			// The issuer funds the deposits of feeless trail events, so role holders need no
+			// balance
+			let depositor = match FeelessTracer::<T>::get() {
+				| Some(tracer) if tracer == who => &verifiable_credential_metadata.issuer,
+				| _ => &who,
+			};
+			let sequence = Self::append_trail_log(depositor, &vc_fingerprint, vc_log)?;
+			VerifiableCredentialCurrentStatus::<T>::insert(&vc_fingerprint, status.clone());
+
+			let event = Event::VerifiableCredentialEvent {
+				vc_fingerprint,
+				origin: who,
+				block_number,
+				status,
+				evidence_hash,
+				account_id,
+				issuer: verifiable_credential_metadata.issuer,
+				sequence,
+				previous_status: current_status,
+			};
+			Self::deposit_event(event);
+			Ok(())
+		}
+
+		/// Ensure `who` may append `status` to the trail of an active verifiable credential,
+		/// without writing to storage. Returns the credential, the role of the tracer and the
+		/// current status of the trail.
+		fn ensure_trace_allowed(
+			who: &T::AccountId,
+			did_uri: Option<&BoundedVec<u8, T::DIDURISize>>,
+			vc_fingerprint: &BoundedVec<u8, T::VCFingerPrintSize>,
+			status: &VerifiableCredentialStatus,
+		) -> Result<
+			(
+				VerifiableCredentialMetadata<
+					T::AccountId,
+					T::BlockNumber,
+					T::PublicKeySize,
+					T::DIDURISize,
+				>,
+				Option<u16>,
+				Option<VerifiableCredentialStatus>,
+			),
+			DispatchError,
+		> {
+			ensure!(!status.is_lineage(), Error::<T>::LineageStatusReserved);
+			let verifiable_credential_metadata = VerifiableCredential::<T>::get(vc_fingerprint)
+				.ok_or(Error::<T>::VerifiableCredentialFingerPrintDoesNotExist)?;
+			ensure!(
+				verifiable_credential_metadata.active == Some(true),
+				Error::<T>::VerifiableCredentialInactive
+			);
+			ensure!(
+				!migrations::v2::is_trail_pending::<T>(vc_fingerprint),
+				Error::<T>::TrailMigrationPending
+			);
+
+			if let VerifiableCredentialStatus::Custom(status_id) = status {
+				ensure!(
+					CustomStatusVocabulary::<T>::contains_key(
+						&verifiable_credential_metadata.issuer,
+						status_id
+					),
+					Error::<T>::CustomStatusNotRegistered
+				);
+			}
+
+			let role = Self::ensure_role_status(
+				&verifiable_credential_metadata.issuer,
+				who,
+				did_uri,
+				status,
+			)?;
+
+			let current_status = VerifiableCredentialCurrentStatus::<T>::get(vc_fingerprint);
+			Self::ensure_status_transition(current_status.as_ref(), status)?;
+
+			Ok((verifiable_credential_metadata, role, current_status))
+		}
+
+		/// Resolve the role of the tracer among the roles of the issuer, preferring the role of
+		/// the device DID over the role of the account. Issuers without roles accept any tracer.
+		fn ensure_role_status(
+			issuer: &T::AccountId,
+			who: &T::AccountId,
+			did_uri: Option<&BoundedVec<u8, T::DIDURISize>>,
+			status: &VerifiableCredentialStatus,
+		) -> Result<Option<u16>, Error<T>> {
+			if RoleCount::<T>::get(issuer) == 0 {
+				return Ok(None)
+			}
+
+			let role_id = did_uri
+				.and_then(|did_uri| {
+					RoleAssignments::<T>::get(issuer, RoleMember::DID(did_uri.clone()))
+				})
+				.or_else(|| RoleAssignments::<T>::get(issuer, RoleMember::Account(who.clone())))
+				.ok_or(Error::<T>::RoleNotAssigned)?;
+			let role = Roles::<T>::get(issuer, role_id).ok_or(Error::<T>::RoleDoesNotExist)?;
+			ensure!(role.allowed_statuses.contains(status), Error::<T>::RoleStatusNotAllowed);
+
+			Ok(Some(role_id))
+		}
+
+		/// Ensure a device is registered, signed the payload for this chain, may append the
+		/// payload status and has not signed too many trail events in the current period
+		fn ensure_device_trace(
+			payload: &DeviceTracePayload<T::Hash, T::VCFingerPrintSize, T::TrailMetadataSize>,
+		) -> Result<
+			Device<
+				T::AccountId,
+				T::BlockNumber,
+				T::DIDURISize,
+				T::DeviceLabelSize,
+				T::MaxDeviceStatuses,
+			>,
+			Error<T>,
+		> {
+			let device =
+				Devices::<T>::get(&payload.device_key).ok_or(Error::<T>::DeviceDoesNotExist)?;
+			ensure!(
+				payload.genesis_hash ==
+					<frame_system::Pallet<T>>::block_hash(T::BlockNumber::zero()),
+				Error::<T>::InvalidDeviceGenesisHash
+			);
+			ensure!(
+				device.allowed_statuses.contains(&payload.status),
+				Error::<T>::DeviceStatusNotAllowed
+			);
+
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			let (period_start, count) = DeviceTraceCount::<T>::get(&payload.device_key);
+			ensure!(
+				block_number >= period_start.saturating_add(T::DeviceTracePeriod::get()) ||
+					count < T::MaxDeviceTracesPerPeriod::get(),
+				Error::<T>::DeviceRateLimited
+			);
+
+			Ok(device)
+		}
+
+		/// Ensure `who` is the issuer or the controller of a verifiable credential
+		fn ensure_credential_owner(
+			who: &T::AccountId,
+			verifiable_credential_metadata: &VerifiableCredentialMetadata<
+				T::AccountId,
+				T::BlockNumber,
+				T::PublicKeySize,
+				T::DIDURISize,
+			>,
+		) -> DispatchResult {
+			ensure!(
+				verifiable_credential_metadata.issuer == *who ||
+					verifiable_credential_metadata.account_id.as_ref() == Some(who),
+				Error::<T>::NotVerifiableCredentialIssuer
+			);
+			Ok(())
+		}
+
+		/// Purge at most `max_entries` of the oldest trail entries kept in state and of the
+		/// archived trail segments of a verifiable credential, refunding the trail deposits and
+		/// clearing the offchain indexed entries of the archived segments.
+		/// Returns the number of purged trail entries and archived segments.
+		fn purge_trail(
+			vc_fingerprint: &BoundedVec<u8, T::VCFingerPrintSize>,
+			max_entries: u32,
+		) -> (u32, u32) {
+			let tail = VerifiableCredentialTrailTail::<T>::get(vc_fingerprint);
+			let end = VerifiableCredentialTrailHead::<T>::get(vc_fingerprint)
+				.min(tail.saturating_add(max_entries));
+			for sequence in tail..end {
+				VerifiableCredentialTrail::<T>::remove(vc_fingerprint, sequence);
+				Self::release_deposit(TrailDeposits::<T>::take(vc_fingerprint, sequence));
+			}
+			if end > tail {
+				VerifiableCredentialTrailTail::<T>::insert(vc_fingerprint, end);
+			}
+			let purged_trail_entries = end - tail;
+
+			let segments = ArchivedTrailSegmentCount::<T>::get(vc_fingerprint);
+			let purged_segments = segments.min(max_entries - purged_trail_entries);
+			for segment in segments - purged_segments..segments {
+				ArchivedTrailSegments::<T>::remove(vc_fingerprint, segment);
+				sp_io::offchain_index::clear(&Self::trail_archive_key(vc_fingerprint, segment));
+			}
+			if purged_segments > 0 {
+				ArchivedTrailSegmentCount::<T>::insert(vc_fingerprint, segments - purged_segments);
+			}
+
+			(purged_trail_entries, purged_segments)
+		}
+
+		/// Ensure a verifiable credential fingerprint is neither in use nor deleted
+		fn ensure_new_fingerprint(
+			vc_fingerprint: &BoundedVec<u8, T::VCFingerPrintSize>,
+		) -> DispatchResult {
+			ensure!(
+				T::FingerprintHasher::is_fingerprint(vc_fingerprint),
+				Error::<T>::MalformedFingerprint
+			);
+			ensure!(
+				!VerifiableCredential::<T>::contains_key(vc_fingerprint) &&
+					!RevokedVerifiableCredential::<T>::contains_key(vc_fingerprint),
+				Error::<T>::VerifiableCredentialFingerPrintExists
+			);
+			ensure!(
+				!VerifiableCredentialTombstone::<T>::contains_key(Self::tombstone_key(
+					vc_fingerprint
+				)),
+				Error::<T>::VerifiableCredentialFingerPrintDeleted
+			);
+			Ok(())
+		}
+
+		/// Anchor a verifiable credential, reserving its deposit from `who`
+		fn insert_verifiable_credential(
+			who: &T::AccountId,
+			vc_fingerprint: &BoundedVec<u8, T::VCFingerPrintSize>,
+			verifiable_credential_metadata: VerifiableCredentialMetadata<
+				T::AccountId,
+				T::BlockNumber,
+				T::PublicKeySize,
+				T::DIDURISize,
+			>,
+		) -> DispatchResult {
+			let deposit = Self::update_deposit(
+				who,
+				None,
+				vc_fingerprint.len() + verifiable_credential_metadata.encoded_size(),
+			)?;
+			CredentialDeposits::<T>::insert(vc_fingerprint, deposit);
+			Self::index_credential(vc_fingerprint, &verifiable_credential_metadata);
+			VerifiableCredential::<T>::insert(vc_fingerprint, verifiable_credential_metadata);
+			Ok(())
+		}
+
+		/// Deposit reserved for a stored item of `bytes` encoded bytes
+		pub fn deposit_for(bytes: usize) -> BalanceOf<T> {
+			T::DepositBase::get()
+				.saturating_add(T::DepositPerByte::get().saturating_mul(bytes.saturated_into()))
+		}
+
+		/// Reserve the deposit of a stored item of `bytes` encoded bytes from `who`. The
+		/// previous deposit of the item is adjusted if `who` holds it and refunded otherwise.
+		fn update_deposit(
+			who: &T::AccountId,
+			previous: Option<DepositOf<T>>,
+			bytes: usize,
+		) -> Result<DepositOf<T>, DispatchError> {
+			let amount = Self::deposit_for(bytes);
+			match previous {
+				| Some(previous) if previous.depositor == *who =>
+					if amount > previous.amount {
+						T::Currency::reserve(who, amount.saturating_sub(previous.amount))?;
+					} else {
+						T::Currency::unreserve(who, previous.amount.saturating_sub(amount));
+					},
+				| previous => {
+					T::Currency::reserve(who, amount)?;
+					Self::release_deposit(previous);
+				},
+			}
+			Ok(StorageDeposit { depositor: who.clone(), amount })
+		}
+
+		/// Account a DID is listed under in `DIDsByAccount`: the signer holding its deposit, or
+		/// its sender for DIDs indexed before deposits were taken
+		fn did_indexed_account<'a>(
+			deposit: &'a Option<DepositOf<T>>,
+			did: &'a DID<T::AccountId, T::BlockNumber, T::MetadataSize>,
+		) -> &'a T::AccountId {
+			deposit.as_ref().map_or(&did.sender_account_id, |deposit| &deposit.depositor)
+		}
+
+		/// Refund a deposit to the account holding it
+		fn release_deposit(deposit: Option<DepositOf<T>>) {
+			if let Some(deposit) = deposit {
+				T::Currency::unreserve(&deposit.depositor, deposit.amount);
+			}
+		}
+
+		/// Ensure a verifiable credential may be split or merged by `who` and return its
+		/// metadata. A credential without a trail is treated as `Created`.
+		fn ensure_lineage_parent(
+			who: &T::AccountId,
+			vc_fingerprint: &BoundedVec<u8, T::VCFingerPrintSize>,
+			status: &VerifiableCredentialStatus,
+		) -> Result<
+			VerifiableCredentialMetadata<
+				T::AccountId,
+				T::BlockNumber,
+				T::PublicKeySize,
+				T::DIDURISize,
+			>,
+			DispatchError,
+		> {
+			let verifiable_credential_metadata = VerifiableCredential::<T>::get(vc_fingerprint)
+				.ok_or(Error::<T>::VerifiableCredentialFingerPrintDoesNotExist)?;
+			ensure!(
+				verifiable_credential_metadata.issuer == *who,
+				Error::<T>::NotVerifiableCredentialIssuer
+			);
+			ensure!(
+				verifiable_credential_metadata.active == Some(true),
+				Error::<T>::VerifiableCredentialInactive
+			);
+			let current_status = VerifiableCredentialCurrentStatus::<T>::get(vc_fingerprint)
+				.unwrap_or(VerifiableCredentialStatus::Created);
+			Self::ensure_status_transition(Some(&current_status), status)?;
+			Ok(verifiable_credential_metadata)
+		}
+
+		fn close_lineage_parent(
+			who: &T::AccountId,
+			vc_fingerprint: &BoundedVec<u8, T::VCFingerPrintSize>,
+			status: VerifiableCredentialStatus,
+		) -> DispatchResult {
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			Self::append_trail_log(
+				who,
+				vc_fingerprint,
+				VerifiableCredentialLog {
+					account_id: None,
+					block_number: Some(block_number),
+					timestamp: Some(Self::now()),
+					status: status.clone(),
+					metadata: None,
+					evidence_hash: None,
+					role: None,
+				},
+			)?;
+			VerifiableCredentialCurrentStatus::<T>::insert(vc_fingerprint, status);
+			Ok(())
+		}
+
+		/// Anchor a child credential inheriting the holder and DIDs of its parent
+		fn insert_lineage_child(
+			who: &T::AccountId,
+			vc_fingerprint: &BoundedVec<u8, T::VCFingerPrintSize>,
+			parent: &VerifiableCredentialMetadata<
+				T::AccountId,
+				T::BlockNumber,
+				T::PublicKeySize,
+				T::DIDURISize,
+			>,
+		) -> DispatchResult {
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			Self::insert_verifiable_credential(
+				who,
+				vc_fingerprint,
+				VerifiableCredentialMetadata {
+					account_id: parent.account_id.clone(),
+					public_key: parent.public_key.clone(),
+					block_number,
+					updated_block_number: block_number,
+					timestamp: Self::now(),
+					updated_timestamp: Self::now(),
+					active: Some(true),
+					issuer: who.clone(),
+					expiry_block_number: parent.expiry_block_number,
+					issuer_did: parent.issuer_did.clone(),
+					subject_did: parent.subject_did.clone(),
+				},
+			)?;
+			Self::close_lineage_parent(who, vc_fingerprint, VerifiableCredentialStatus::Created)
+		}
+
+		/// Walk the lineage of a verifiable credential breadth first, returning at most `limit`
+		/// fingerprints
+		fn walk_lineage(
+			vc_fingerprint: &[u8],
+			limit: u32,
+			relatives: impl Fn(
+				&BoundedVec<u8, T::VCFingerPrintSize>,
+			) -> Vec<BoundedVec<u8, T::VCFingerPrintSize>>,
+		) -> Vec<Vec<u8>> {
+			let vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize> =
+				match vc_fingerprint.to_vec().try_into() {
+					Ok(vc_fingerprint) => vc_fingerprint,
+					Err(_) => return Vec::new(),
+				};
+			let mut visited = BTreeSet::new();
+			visited.insert(vc_fingerprint.to_vec());
+			let mut queue = VecDeque::new();
+			queue.push_back(vc_fingerprint);
+
+			let mut lineage = Vec::new();
+			while let Some(vc_fingerprint) = queue.pop_front() {
+				for relative in relatives(&vc_fingerprint) {
+					if lineage.len() >= limit as usize {
+						return lineage
+					}
+					if visited.insert(relative.to_vec()) {
+						lineage.push(relative.to_vec());
+						queue.push_back(relative);
+					}
+				}
+			}
+			lineage
+		}
+
+		/// Ancestors of a verifiable credential created by split and merge
+		pub fn credential_ancestors(vc_fingerprint: &[u8], limit: u32) -> Vec<Vec<u8>> {
+			Self::walk_lineage(vc_fingerprint, limit, |vc_fingerprint| {
+				CredentialParents::<T>::iter_key_prefix(vc_fingerprint).collect()
+			})
+		}
+
+		/// Descendants of a verifiable credential created by split and merge
+		pub fn credential_descendants(vc_fingerprint: &[u8], limit: u32) -> Vec<Vec<u8>> {
+			Self::walk_lineage(vc_fingerprint, limit, |vc_fingerprint| {
+				CredentialChildren::<T>::iter_key_prefix(vc_fingerprint).collect()
+			})
+		}
+
+		/// Migration chain of a verifiable credential ordered from the original fingerprint to
+		/// the latest one, holding at most `limit` fingerprints
+		pub fn credential_migration_chain(vc_fingerprint: &[u8], limit: u32) -> Vec<Vec<u8>> {
+			let mut vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize> =
+				match vc_fingerprint.to_vec().try_into() {
+					Ok(vc_fingerprint) => vc_fingerprint,
+					Err(_) => return Vec::new(),
+				};
+			let mut steps = 0;
+			while let Some(previous) = CredentialMigratedFrom::<T>::get(&vc_fingerprint) {
+				if steps >= limit {
+					break
+				}
+				vc_fingerprint = previous;
+				steps += 1;
+			}
+
+			let mut chain = Vec::new();
+			chain.push(vc_fingerprint.to_vec());
+			while let Some(next) = CredentialMigratedTo::<T>::get(&vc_fingerprint) {
+				if chain.len() >= limit as usize {
+					break
+				}
+				chain.push(next.to_vec());
+				vc_fingerprint = next;
+			}
+			chain
+		}
+
+		/// Append a log entry to the trail of a verifiable credential, reserving its deposit from
+		/// `who`, and return its sequence number
+		fn append_trail_log(
+			who: &T::AccountId,
+			vc_fingerprint: &BoundedVec<u8, T::VCFingerPrintSize>,
+			vc_log: VerifiableCredentialLog<
+				T::AccountId,
+				T::BlockNumber,
+				T::Hash,
+				T::TrailMetadataSize,
+			>,
+		) -> Result<u32, DispatchError> {
+			VerifiableCredentialTrailHead::<T>::try_mutate(vc_fingerprint, |head| {
+				let sequence = *head;
+				*head =
+					head.checked_add(1).ok_or(Error::<T>::VerifiableCredentialLogLimitReached)?;
+				let deposit = Self::update_deposit(who, None, vc_log.encoded_size())?;
+				TrailDeposits::<T>::insert(vc_fingerprint, sequence, deposit);
+				VerifiableCredentialTrail::<T>::insert(vc_fingerprint, sequence, vc_log);
+				Ok(sequence)
+			})
+		}
+
+		/// Page through the trail of a verifiable credential, starting at the `start` sequence
+		/// number
+		pub fn verifiable_credential_trail(
+			vc_fingerprint: &[u8],
+			start: u32,
+			limit: u32,
+		) -> Vec<VerifiableCredentialLog<T::AccountId, T::BlockNumber, T::Hash, T::TrailMetadataSize>>
+		{
+			let vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize> =
+				match vc_fingerprint.to_vec().try_into() {
+					Ok(vc_fingerprint) => vc_fingerprint,
+					Err(_) => return Vec::new(),
+				};
+			let head = VerifiableCredentialTrailHead::<T>::get(&vc_fingerprint);
+			(start..head.min(start.saturating_add(limit)))
+				.filter_map(|sequence| {
+					VerifiableCredentialTrail::<T>::get(&vc_fingerprint, sequence)
+				})
+				.collect()
+		}
+
+		/// Custom status vocabulary registered by an issuer, as status id to label
+		pub fn custom_status_vocabulary(issuer: &T::AccountId) -> Vec<(u16, Vec<u8>)> {
+			let mut vocabulary: Vec<(u16, Vec<u8>)> =
+				CustomStatusVocabulary::<T>::iter_prefix(issuer)
+					.map(|(status_id, label)| (status_id, label.into_inner()))
+					.collect();
+			vocabulary.sort_by_key(|(status_id, _)| *status_id);
+			vocabulary
+		}
+
+		/// Query the trail of a verifiable credential from the `cursor` sequence number, returning
+		/// at most `limit` entries matching the filter along with the current status summary.
+		/// At most `TRAIL_QUERY_SCAN_LIMIT` entries are scanned per page. Returns `None` if the
+		/// verifiable credential has neither metadata nor a trail.
+		pub fn query_trail(
+			vc_fingerprint: &[u8],
+			filter: TrailFilter<T::AccountId, T::BlockNumber>,
+			cursor: Option<u32>,
+			limit: u32,
+		) -> Option<TrailPage<T::AccountId, T::BlockNumber, T::Hash, T::TrailMetadataSize>> {
+			let vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize> =
+				vc_fingerprint.to_vec().try_into().ok()?;
+			let head = VerifiableCredentialTrailHead::<T>::get(&vc_fingerprint);
+			let active = VerifiableCredential::<T>::get(&vc_fingerprint).map(|vc| vc.active);
+			if head == 0 && active.is_none() {
+				return None
+			}
+
+			let tail = VerifiableCredentialTrailTail::<T>::get(&vc_fingerprint);
+			let start = cursor.unwrap_or_default().max(tail);
+			let end = head.min(start.saturating_add(TRAIL_QUERY_SCAN_LIMIT));
+			let mut entries = Vec::new();
+			let mut next_cursor = None;
+			for sequence in start..end {
+				if entries.len() as u32 >= limit {
+					next_cursor = Some(sequence);
+					break
+				}
+				if let Some(vc_log) = VerifiableCredentialTrail::<T>::get(&vc_fingerprint, sequence)
+				{
+					if filter.matches(&vc_log) {
+						entries.push((sequence, vc_log));
+					}
+				}
+			}
+			if next_cursor.is_none() && end < head {
+				next_cursor = Some(end);
+			}
+
+			let last_block_number = head
+				.checked_sub(1)
+				.and_then(|last| VerifiableCredentialTrail::<T>::get(&vc_fingerprint, last))
+				.and_then(|vc_log| vc_log.block_number);
+			let summary = TrailSummary {
+				current_status: VerifiableCredentialCurrentStatus::<T>::get(&vc_fingerprint),
+				active: active.flatten(),
+				trail_length: head,
+				archived_entries: tail,
+				last_block_number,
+			};
+			Some(TrailPage { entries, next_cursor, summary })
+		}
+
+		/// Ensure `next` may follow the `current` status of a verifiable credential trail
+		fn ensure_status_transition(
+			current: Option<&VerifiableCredentialStatus>,
+			next: &VerifiableCredentialStatus,
+		) -> DispatchResult {
+			match current {
+				None => ensure!(
+					matches!(
+						next,
+						VerifiableCredentialStatus::Pending | VerifiableCredentialStatus::Created
+					),
+					Error::<T>::InvalidInitialStatus
+				),
+				Some(VerifiableCredentialStatus::Frozen) =>
+					return Err(Error::<T>::VerifiableCredentialFrozen.into()),
+				Some(current) if current.is_terminal() =>
+					return Err(Error::<T>::VerifiableCredentialTerminated.into()),
+				Some(current) => {
+					ensure!(
+						*next != VerifiableCredentialStatus::Resumed ||
+							*current == VerifiableCredentialStatus::Suspended,
+						Error::<T>::ResumeWithoutSuspend
+					);
+					ensure!(current.can_transition_to(next), Error::<T>::InvalidStatusTransition);
+				},
+			}
+			Ok(())
+		}
+
+		/// Ensure the issuer and subject DIDs referenced by a verifiable credential exist
+		fn ensure_credential_dids_exist(
+			verifiable_credential_input_metadata: &VerifiableCredentialMetadataPayload<
+				T::AccountId,
+				T::BlockNumber,
+				T::PublicKeySize,
+				T::DIDURISize,
+			>,
+		) -> DispatchResult {
+			for did_uri in [
+				&verifiable_credential_input_metadata.issuer_did,
+				&verifiable_credential_input_metadata.subject_did,
+			]
+			.into_iter()
+			.flatten()
+			{
+				ensure!(DIDDocument::<T>::contains_key(did_uri), Error::<T>::DIDDoesNotExist);
+			}
+			Ok(())
+		}
+
+		fn index_credential(
+			vc_fingerprint: &BoundedVec<u8, T::VCFingerPrintSize>,
+			verifiable_credential_metadata: &VerifiableCredentialMetadata<
+				T::AccountId,
+				T::BlockNumber,
+				T::PublicKeySize,
+				T::DIDURISize,
+			>,
+		) {
+			CredentialsByHolder::<T>::insert(
+				Self::holder_key(&verifiable_credential_metadata.public_key),
+				vc_fingerprint,
+				(),
+			);
+			if let Some(issuer_did) = &verifiable_credential_metadata.issuer_did {
+				CredentialsByIssuerDID::<T>::insert(issuer_did, vc_fingerprint, ());
+			}
+			if let Some(subject_did) = &verifiable_credential_metadata.subject_did {
+				CredentialsBySubjectDID::<T>::insert(subject_did, vc_fingerprint, ());
+			}
+		}
+
+		fn unindex_credential(
+			vc_fingerprint: &BoundedVec<u8, T::VCFingerPrintSize>,
+			verifiable_credential_metadata: &VerifiableCredentialMetadata<
+				T::AccountId,
+				T::BlockNumber,
+				T::PublicKeySize,
+				T::DIDURISize,
+			>,
+		) {
+			CredentialsByHolder::<T>::remove(
+				Self::holder_key(&verifiable_credential_metadata.public_key),
+				vc_fingerprint,
+			);
+			if let Some(issuer_did) = &verifiable_credential_metadata.issuer_did {
+				CredentialsByIssuerDID::<T>::remove(issuer_did, vc_fingerprint);
+			}
+			if let Some(subject_did) = &verifiable_credential_metadata.subject_did {
+				CredentialsBySubjectDID::<T>::remove(subject_did, vc_fingerprint);
+			}
+		}
+
+		/// Remove the split, merge and migration links of a verifiable credential from both ends,
+		/// returning the number of removed links
+		fn remove_lineage(vc_fingerprint: &BoundedVec<u8, T::VCFingerPrintSize>) -> u32 {
+			let mut removed_links = 0u32;
+			for (parent, ()) in CredentialParents::<T>::drain_prefix(vc_fingerprint) {
+				CredentialChildren::<T>::remove(&parent, vc_fingerprint);
+				removed_links = removed_links.saturating_add(1);
+			}
+			for (child, ()) in CredentialChildren::<T>::drain_prefix(vc_fingerprint) {
+				CredentialParents::<T>::remove(&child, vc_fingerprint);
+				removed_links = removed_links.saturating_add(1);
+			}
+			if let Some(new_vc_fingerprint) = CredentialMigratedTo::<T>::take(vc_fingerprint) {
+				CredentialMigratedFrom::<T>::remove(&new_vc_fingerprint);
+				removed_links = removed_links.saturating_add(1);
+			}
+			if let Some(previous_vc_fingerprint) = CredentialMigratedFrom::<T>::take(vc_fingerprint)
+			{
+				CredentialMigratedTo::<T>::remove(&previous_vc_fingerprint);
+				removed_links = removed_links.saturating_add(1);
+			}
+			removed_links
+		}
+
+		/// Page through the verifiable credentials issued by a DID, starting after the
+		/// `start_after` fingerprint
+		pub fn credentials_by_issuer_did(
+			did_uri: &[u8],
+			start_after: Option<Vec<u8>>,
+			limit: u32,
+		) -> Vec<Vec<u8>> {
+			let did_uri: BoundedVec<u8, T::DIDURISize> = match did_uri.to_vec().try_into() {
+				Ok(did_uri) => did_uri,
+				Err(_) => return Vec::new(),
+			};
+			let iter = match start_after
+				.and_then(|fp| BoundedVec::<u8, T::VCFingerPrintSize>::try_from(fp).ok())
+			{
+				Some(fp) => CredentialsByIssuerDID::<T>::iter_key_prefix_from(
+					&did_uri,
+					CredentialsByIssuerDID::<T>::hashed_key_for(&did_uri, &fp),
+				),
+				None => CredentialsByIssuerDID::<T>::iter_key_prefix(&did_uri),
+			};
+			iter.take(limit as usize).map(|fp| fp.into_inner()).collect()
+		}
+
+		/// Page through the verifiable credentials issued to a DID, starting after the
+		/// `start_after` fingerprint
+		pub fn credentials_by_subject_did(
+			did_uri: &[u8],
+			start_after: Option<Vec<u8>>,
+			limit: u32,
+		) -> Vec<Vec<u8>> {
+			let did_uri: BoundedVec<u8, T::DIDURISize> = match did_uri.to_vec().try_into() {
+				Ok(did_uri) => did_uri,
+				Err(_) => return Vec::new(),
+			};
+			let iter = match start_after
+				.and_then(|fp| BoundedVec::<u8, T::VCFingerPrintSize>::try_from(fp).ok())
+			{
+				Some(fp) => CredentialsBySubjectDID::<T>::iter_key_prefix_from(
+					&did_uri,
+					CredentialsBySubjectDID::<T>::hashed_key_for(&did_uri, &fp),
+				),
+				None => CredentialsBySubjectDID::<T>::iter_key_prefix(&did_uri),
+			};
+			iter.take(limit as usize).map(|fp| fp.into_inner()).collect()
+		}
+
+		/// Page through the verifiable credentials bound to a holder public key, starting after
+		/// the `start_after` fingerprint
+		pub fn credentials_by_holder(
+			public_key: &[u8],
+			start_after: Option<Vec<u8>>,
+			limit: u32,
+		) -> Vec<Vec<u8>> {
+			let holder_key = Self::holder_key(public_key);
+			let iter = match start_after
+				.and_then(|fp| BoundedVec::<u8, T::VCFingerPrintSize>::try_from(fp).ok())
+			{
+				Some(fp) => CredentialsByHolder::<T>::iter_key_prefix_from(
+					holder_key,
+					CredentialsByHolder::<T>::hashed_key_for(holder_key, &fp),
+				),
+				None => CredentialsByHolder::<T>::iter_key_prefix(holder_key),
+			};
+			iter.take(limit as usize).map(|fp| fp.into_inner()).collect()
+		}
+
+		/// Page through the DID documents last created or updated by an account, starting after
+		/// the `start_after` DID URI
+		pub fn dids_by_account(
+			account_id: &T::AccountId,
+			start_after: Option<Vec<u8>>,
+			limit: u32,
+		) -> Vec<Vec<u8>> {
+			let iter = match start_after
+				.and_then(|did_uri| BoundedVec::<u8, T::DIDURISize>::try_from(did_uri).ok())
+			{
+				Some(did_uri) => DIDsByAccount::<T>::iter_key_prefix_from(
+					account_id,
+					DIDsByAccount::<T>::hashed_key_for(account_id, &did_uri),
+				),
+				None => DIDsByAccount::<T>::iter_key_prefix(account_id),
+			};
+			iter.take(limit as usize).map(|did_uri| did_uri.into_inner()).collect()
+		}
+
+		/// Key of a holder public key in the `CredentialsByHolder` index
+		pub fn holder_key(public_key: &[u8]) -> T::Hash {
+			T::Hashing::hash(public_key)
+		}
+
+		/// Multihash fingerprint of canonical credential bytes
+		pub fn fingerprint_of(content: &[u8]) -> Vec<u8> {
+			T::FingerprintHasher::fingerprint(content)
+		}
+
+		/// Tombstone key of a deleted verifiable credential fingerprint
+		pub fn tombstone_key(vc_fingerprint: &BoundedVec<u8, T::VCFingerPrintSize>) -> T::Hash {
+			T::Hashing::hash(vc_fingerprint)
+		}
+
+		/// Merkle leaf of a verifiable credential fingerprint in a credential batch
+		pub fn batch_leaf(vc_fingerprint: &[u8]) -> T::Hash {
+			T::Hashing::hash(vc_fingerprint)
+		}
+
+		/// Fold a merkle proof into the root. Sibling pairs are hashed in sorted order so the
+		/// proof does not need to carry the position of each node.
+		pub fn merkle_root(leaf: T::Hash, proof: &[T::Hash]) -> T::Hash {
+			proof.iter().fold(leaf, |node, sibling| Self::hash_pair(node, *sibling))
+		}
+
+		/// Hash a pair of merkle nodes in sorted order
+		fn hash_pair(left: T::Hash, right: T::Hash) -> T::Hash {
+			if left <= right {
+				T::Hashing::hash_of(&(left, right))
+			} else {
+				T::Hashing::hash_of(&(right, left))
+			}
+		}
+
+		/// Hash the nodes of a merkle tree level pairwise into the level above it. A node
+		/// without a sibling is promoted unchanged.
+		fn merkle_parents(nodes: &[T::Hash]) -> Vec<T::Hash> {
+			nodes
+				.chunks(2)
+				.map(|pair| match pair {
+					| [left, right] => Self::hash_pair(*left, *right),
+					| _ => pair[0],
+				})
+				.collect()
+		}
+
+		/// Build the merkle root of a list of leaves
+		pub fn build_merkle_root(mut leaves: Vec<T::Hash>) -> T::Hash {
+			while leaves.len() > 1 {
+				leaves = Self::merkle_parents(&leaves);
+			}
+			leaves.pop().unwrap_or_default()
+		}
+
+		/// Build the merkle proof of the leaf at `index`, to be folded with `merkle_root`
+		pub fn build_merkle_proof(mut leaves: Vec<T::Hash>, mut index: usize) -> Vec<T::Hash> {
+			let mut proof = Vec::new();
+			while leaves.len() > 1 {
+				if let Some(sibling) = leaves.get(index ^ 1) {
+					proof.push(*sibling);
+				}
+				leaves = Self::merkle_parents(&leaves);
+				index /= 2;
+			}
+			proof
+		}
+
+		/// Merkle leaf of an archived trail entry
+		pub fn trail_leaf(
+			sequence: u32,
+			vc_log: &VerifiableCredentialLog<
+				T::AccountId,
+				T::BlockNumber,
+				T::Hash,
+				T::TrailMetadataSize,
+			>,
+		) -> T::Hash {
+			T::Hashing::hash_of(&(sequence, vc_log))
+		}
+
+		/// Offchain indexing key of the entries pruned into an archived trail segment
+		pub fn trail_archive_key(vc_fingerprint: &[u8], segment: u32) -> Vec<u8> {
+			(TRAIL_ARCHIVE_PREFIX, vc_fingerprint, segment).encode()
+		}
+
+		/// Verify that a historical trail entry belongs to an archived trail segment
+		pub fn verify_archived_trail_log(
+			vc_fingerprint: &[u8],
+			segment: u32,
+			sequence: u32,
+			vc_log: &VerifiableCredentialLog<
+				T::AccountId,
+				T::BlockNumber,
+				T::Hash,
+				T::TrailMetadataSize,
+			>,
+			proof: &[T::Hash],
+		) -> bool {
+			let vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize> =
+				match vc_fingerprint.to_vec().try_into() {
+					Ok(vc_fingerprint) => vc_fingerprint,
+					Err(_) => return false,
+				};
+			let archived = match ArchivedTrailSegments::<T>::get(&vc_fingerprint, segment) {
+				| Some(archived) => archived,
+				| None => return false,
+			};
+			(archived.start..archived.start.saturating_add(archived.count)).contains(&sequence) &&
+				Self::merkle_root(Self::trail_leaf(sequence, vc_log), proof) ==
+					archived.merkle_root
+		}
+
+		/// Verify the inclusion proof of a verifiable credential fingerprint in a credential
+		/// batch, taking batch and per credential revocations into account
+		pub fn verify_batch_credential(
+			batch_id: u64,
+			vc_fingerprint: &[u8],
+			proof: &[T::Hash],
+		) -> Option<BatchCredentialStatus> {
+			let batch = CredentialBatch::<T>::get(batch_id)?;
+			let leaf = Self::batch_leaf(vc_fingerprint);
+
+			if Self::merkle_root(leaf, proof) != batch.merkle_root {
+				return Some(BatchCredentialStatus::InvalidProof)
+			}
+			if batch.revoked_block_number.is_some() ||
+				RevokedBatchCredential::<T>::contains_key(batch_id, leaf)
+			{
+				return Some(BatchCredentialStatus::Revoked)
+			}
+			Some(BatchCredentialStatus::Valid)
+		}
+
+		/// Whether `who` may sign `trace_credential` without paying fees: it holds a role of the
+		/// verifiable credential issuer allowing the status and has feeless traces left in the
+		/// current era
+		pub fn is_feeless_trace(
+			who: &T::AccountId,
+			vc_fingerprint: &BoundedVec<u8, T::VCFingerPrintSize>,
+			status: &VerifiableCredentialStatus,
+		) -> bool {
+			VerifiableCredential::<T>::get(vc_fingerprint).map_or(false, |vc| {
+				matches!(Self::ensure_role_status(&vc.issuer, who, None, status), Ok(Some(_)))
+			}) && Self::feeless_traces_left(who) > 0
+		}
+
+		/// Feeless trail events `who` may still sign in the current era
+		pub fn feeless_traces_left(who: &T::AccountId) -> u32 {
+			let (era, count) = FeelessTraceCount::<T>::get(who);
+			if era == Self::feeless_trace_era() {
+				T::MaxFeelessTracesPerEra::get().saturating_sub(count)
+			} else {
+				T::MaxFeelessTracesPerEra::get()
+			}
+		}
+
+		/// Current feeless trace era and the feeless trail events `who` signed in it, i.e. the
+		/// quota slot the next feeless trace of `who` takes
+		pub fn feeless_trace_slot(who: &T::AccountId) -> (T::BlockNumber, u32) {
+			let current_era = Self::feeless_trace_era();
+			let (era, count) = FeelessTraceCount::<T>::get(who);
+			(current_era, if era == current_era { count } else { 0 })
+		}
+
+		/// Count a feeless trail event against the quota of `who` and mark it as being
+		/// dispatched until `end_feeless_trace`
+		pub fn note_feeless_trace(who: &T::AccountId) {
+			FeelessTracer::<T>::put(who);
+			let current_era = Self::feeless_trace_era();
+			FeelessTraceCount::<T>::mutate(who, |(era, count)| {
+				if *era != current_era {
+					*era = current_era;
+					*count = 0;
+				}
+				*count = count.saturating_add(1);
+			});
+		}
+
+		/// End the dispatch of the feeless trail event marked by `note_feeless_trace`
+		pub fn end_feeless_trace() {
+			FeelessTracer::<T>::kill();
+		}
+
+		fn feeless_trace_era() -> T::BlockNumber {
+			Self::window_index(T::FeelessTraceEra::get())
+		}
+
+		/// Index of the current window of `length` blocks
+		fn window_index(length: T::BlockNumber) -> T::BlockNumber {
+			<frame_system::Pallet<T>>::block_number()
+				.checked_div(&length)
+				.unwrap_or_default()
+		}
+
+		/// Count an operation in a rate limit counter, resetting it in a new window. Returns
+		/// false once `max` operations were counted in the current window.
+		fn count_operation(counter: &mut (T::BlockNumber, u32), max: u32) -> bool {
+			let window = Self::window_index(T::RateLimitWindow::get());
+			if counter.0 != window {
+				*counter = (window, 0);
+			}
+			if counter.1 >= max {
+				return false
+			}
+			counter.1 += 1;
+			true
+		}
+
+		/// Ensure `who` signed fewer than `MaxOperationsPerWindow` calls in the current window
+		fn ensure_account_rate_limit(who: &T::AccountId) -> DispatchResult {
+			if RateLimitExempt::<T>::contains_key(who) {
+				return Ok(())
+			}
+			AccountOperations::<T>::try_mutate(who, |counter| {
+				ensure!(
+					Self::count_operation(counter, T::MaxOperationsPerWindow::get()),
+					Error::<T>::AccountRateLimited
+				);
+				Ok(())
+			})
+		}
+
+		/// Ensure `who` appended fewer than `MaxIssuerTracesPerWindow` trail events to the
+		/// verifiable credentials of `issuer` in the current window. Tracers are counted
+		/// separately, so no tracer can use up the window of another.
+		fn ensure_issuer_rate_limit(issuer: &T::AccountId, who: &T::AccountId) -> DispatchResult {
+			if RateLimitExempt::<T>::contains_key(issuer) {
+				return Ok(())
+			}
+			IssuerTraces::<T>::try_mutate(issuer, who, |counter| {
+				ensure!(
+					Self::count_operation(counter, T::MaxIssuerTracesPerWindow::get()),
+					Error::<T>::IssuerRateLimited
+				);
+				Ok(())
+			})
+		}
+
+		/// Whether `who` may append a trail event to the verifiable credentials of `issuer`
+		/// without exceeding `MaxIssuerTracesPerWindow`, without counting it
+		fn issuer_may_trace(issuer: &T::AccountId, who: &T::AccountId) -> bool {
+			RateLimitExempt::<T>::contains_key(issuer) ||
+				Self::count_operation(
+					&mut IssuerTraces::<T>::get(issuer, who),
+					T::MaxIssuerTracesPerWindow::get(),
+				)
+		}
+
+		/// Current wall-clock time in milliseconds since the Unix epoch
+		fn now() -> u64 {
+			T::TimeProvider::now().as_millis().saturated_into::<u64>()
+		}
+
+		/// Render milliseconds since the Unix epoch as an ISO 8601 UTC timestamp, e.g.
+		/// `2002-01-01T20:20:20Z`
+		pub fn iso8601(timestamp: u64) -> Vec<u8> {
+			let seconds = timestamp / 1000;
+			let (days, seconds_of_day) = (seconds / 86_400, seconds % 86_400);
+
+			// Civil date from days since the Unix epoch, counting eras of 400 years from
+			// 0000-03-01 so leap days fall at the end of each year
+			let days = days + 719_468;
+			let era = days / 146_097;
+			let day_of_era = days % 146_097;
+			let year_of_era =
+				(day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+			let day_of_year =
+				day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+			let shifted_month = (5 * day_of_year + 2) / 153;
+			let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+			let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+			let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+			let mut rendered = Vec::with_capacity(20);
+			for (value, width, separator) in [
+				(year, 4, b'-'),
+				(month, 2, b'-'),
+				(day, 2, b'T'),
+				(seconds_of_day / 3600, 2, b':'),
+				(seconds_of_day % 3600 / 60, 2, b':'),
+				(seconds_of_day % 60, 2, b'Z'),
+			] {
+				for position in (0..width).rev() {
+					rendered.push(b'0' + (value / 10u64.pow(position) % 10) as u8);
+				}
+				rendered.push(separator);
+			}
+			rendered
+		}
+
+		/// Resolve a DID Document with its `created` and `updated` metadata rendered as ISO 8601
+		pub fn resolve_did(did_uri: &[u8]) -> Option<DIDResolution<T::AccountId, T::BlockNumber>> {
+			let did_uri: BoundedVec<u8, T::DIDURISize> = did_uri.to_vec().try_into().ok()?;
+			let did = DIDDocument::<T>::get(&did_uri)?;
+			Some(DIDResolution {
+				did_uri: did_uri.into_inner(),
+				did_resolution_metadata: did.did_resolution_metadata.map(|m| m.into_inner()),
+				did_document_metadata: did.did_document_metadata.map(|m| m.into_inner()),
+				did_ref: did.did_ref.map(|did_ref| did_ref.into_inner()),
+				sender_account_id: did.sender_account_id,
+				block_number: did.block_number,
+				updated_block_number: did.updated_block_number,
+				created: Self::iso8601(did.timestamp),
+				updated: Self::iso8601(did.updated_timestamp),
+			})
+		}
+
+		/// Resolve a verifiable credential with its `created` and `updated` metadata rendered as
+		/// ISO 8601
+		pub fn resolve_verifiable_credential(
+			vc_fingerprint: &[u8],
+		) -> Option<VerifiableCredentialResolution<T::AccountId, T::BlockNumber>> {
+			let vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize> =
+				vc_fingerprint.to_vec().try_into().ok()?;
+			let vc = VerifiableCredential::<T>::get(&vc_fingerprint)?;
+			Some(VerifiableCredentialResolution {
+				vc_fingerprint: vc_fingerprint.into_inner(),
+				account_id: vc.account_id,
+				public_key: vc.public_key.into_inner(),
+				issuer: vc.issuer,
+				issuer_did: vc.issuer_did.map(|did_uri| did_uri.into_inner()),
+				subject_did: vc.subject_did.map(|did_uri| did_uri.into_inner()),
+				active: vc.active,
+				block_number: vc.block_number,
+				updated_block_number: vc.updated_block_number,
+				expiry_block_number: vc.expiry_block_number,
+				created: Self::iso8601(vc.timestamp),
+				updated: Self::iso8601(vc.updated_timestamp),
+			})
+		}
+
+		/// Validity and current trail status of a verifiable credential at the current block.
+		/// Returns `None` if the verifiable credential was never anchored.
+		pub fn credential_status(
+			vc_fingerprint: &[u8],
+		) -> Option<CredentialStatusReport<T::AccountId, T::BlockNumber>> {
+			let vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize> =
+				vc_fingerprint.to_vec().try_into().ok()?;
+			let current_status = VerifiableCredentialCurrentStatus::<T>::get(&vc_fingerprint);
+
+			if let Some(deleted_block_number) =
+				VerifiableCredentialTombstone::<T>::get(Self::tombstone_key(&vc_fingerprint))
+			{
+				return Some(CredentialStatusReport {
+					validity: CredentialValidity::Deleted,
+					current_status,
+					issuer: None,
+					expiry_block_number: None,
+					revoked_block_number: None,
+					deleted_block_number: Some(deleted_block_number),
+				})
+			}
+			if let Some(revocation) = RevokedVerifiableCredential::<T>::get(&vc_fingerprint) {
+				return Some(CredentialStatusReport {
+					validity: CredentialValidity::Revoked,
+					current_status,
+					issuer: Some(revocation.issuer),
+					expiry_block_number: None,
+					revoked_block_number: Some(revocation.revoked_block_number),
+					deleted_block_number: None,
+				})
+			}
+
+			let vc = VerifiableCredential::<T>::get(&vc_fingerprint)?;
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			let validity = match (vc.expiry_block_number, vc.active) {
+				| (Some(expiry), _) if expiry <= block_number => CredentialValidity::Expired,
+				| (_, Some(false)) => CredentialValidity::Inactive,
+				| _ => CredentialValidity::Valid,
+			};
+			Some(CredentialStatusReport {
+				validity,
+				current_status,
+				issuer: Some(vc.issuer),
+				expiry_block_number: vc.expiry_block_number,
+				revoked_block_number: None,
+				deleted_block_number: None,
+			})
+		}
+
+		/// Storage keys holding the state of a DID Document, watched by RPC subscriptions
+		pub fn did_storage_keys(did_uri: &[u8]) -> Vec<Vec<u8>> {
+			let did_uri: BoundedVec<u8, T::DIDURISize> = match did_uri.to_vec().try_into() {
+				Ok(did_uri) => did_uri,
+				Err(_) => return Vec::new(),
+			};
+			vec![DIDDocument::<T>::hashed_key_for(&did_uri)]
+		}
+
+		/// Storage keys holding the metadata, revocation, current trail status and tombstone of a
+		/// verifiable credential, watched by RPC subscriptions
+		pub fn credential_storage_keys(vc_fingerprint: &[u8]) -> Vec<Vec<u8>> {
+			let vc_fingerprint: BoundedVec<u8, T::VCFingerPrintSize> =
+				match vc_fingerprint.to_vec().try_into() {
+					Ok(vc_fingerprint) => vc_fingerprint,
+					Err(_) => return Vec::new(),
+				};
+			vec![
+				VerifiableCredential::<T>::hashed_key_for(&vc_fingerprint),
+				RevokedVerifiableCredential::<T>::hashed_key_for(&vc_fingerprint),
+				VerifiableCredentialCurrentStatus::<T>::hashed_key_for(&vc_fingerprint),
+				VerifiableCredentialTombstone::<T>::hashed_key_for(Self::tombstone_key(
+					&vc_fingerprint,
+				)),
+			]
 		}
 	}
 }
@@ -0,0 +1,278 @@
+/// Storage migrations of the verifiable pallet
+pub mod v2 {
+	use crate::*;
+	use codec::{Decode, Encode};
+	use frame_support::{
+		storage::StoragePrefixedMap,
+		storage_alias,
+		traits::{GetStorageVersion, OnRuntimeUpgrade},
+		weights::Weight,
+		Blake2_128Concat, ReversibleStorageHasher,
+	};
+
+	/// Trail entries migrated per block. A trail is always migrated whole, so a block may
+	/// migrate more entries to finish the trail it started.
+	pub const TRAIL_MIGRATION_STEP: u32 = 1_000;
+
+	/// Storage layout of version 1
+	pub mod v1 {
+		use super::*;
+
+		#[derive(Encode, Decode)]
+		pub struct DID<AccountId, BlockNumber, MetadataSize>
+		where
+			MetadataSize: Get<u32>,
+		{
+			pub signatures: BoundedVec<u8, MetadataSize>,
+			pub did_resolution_metadata: Option<BoundedVec<u8, MetadataSize>>,
+			pub did_document_metadata: Option<BoundedVec<u8, MetadataSize>>,
+			pub block_number: BlockNumber,
+			pub updated_block_number: BlockNumber,
+			pub did_ref: Option<BoundedVec<u8, MetadataSize>>,
+			pub sender_account_id: AccountId,
+		}
+
+		#[derive(Encode, Decode)]
+		pub struct VerifiableCredentialMetadata<AccountId, BlockNumber, PublicKeySize>
+		where
+			PublicKeySize: Get<u32>,
+		{
+			pub account_id: Option<AccountId>,
+			pub public_key: BoundedVec<u8, PublicKeySize>,
+			pub block_number: BlockNumber,
+			pub updated_block_number: BlockNumber,
+			pub active: Option<bool>,
+		}
+
+		#[derive(Encode, Decode)]
+		pub struct VerifiableCredentialLog<AccountId, BlockNumber> {
+			pub account_id: Option<AccountId>,
+			pub status: VerifiableCredentialStatus,
+			pub block_number: Option<BlockNumber>,
+		}
+
+		pub type DIDOf<T> = DID<
+			<T as frame_system::Config>::AccountId,
+			<T as frame_system::Config>::BlockNumber,
+			<T as Config>::MetadataSize,
+		>;
+
+		pub type VerifiableCredentialMetadataOf<T> = VerifiableCredentialMetadata<
+			<T as frame_system::Config>::AccountId,
+			<T as frame_system::Config>::BlockNumber,
+			<T as Config>::PublicKeySize,
+		>;
+
+		pub type VerifiableCredentialLogOf<T> = VerifiableCredentialLog<
+			<T as frame_system::Config>::AccountId,
+			<T as frame_system::Config>::BlockNumber,
+		>;
+
+		/// Trail of a verifiable credential, kept as a single bounded vector per fingerprint
+		#[storage_alias]
+		pub type VerifiableCredentialTrail<T: Config> = StorageMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			BoundedVec<u8, <T as Config>::VCFingerPrintSize>,
+			BoundedVec<VerifiableCredentialLogOf<T>, <T as Config>::MetadataSize>,
+		>;
+	}
+
+	/// Raw storage key of the last version 1 trail migrated, present while trails are migrated
+	#[storage_alias]
+	pub type TrailMigrationCursor<T: Config> = StorageValue<Pallet<T>, Vec<u8>>;
+
+	/// DID documents and verifiable credentials `MigrateToV2` may translate within the upgrade
+	/// block. Version 1 chains hold far fewer records, and `pre_upgrade` checks the bound.
+	pub const MAX_UPGRADE_RECORDS: u32 = 10_000;
+
+	/// Migrates DID documents and verifiable credentials from version 1 to version 2 and starts
+	/// the migration of the trails, which `migrate_trails` continues block by block. DID
+	/// documents and verifiable credentials are translated in the upgrade block, which holds at
+	/// most `MAX_UPGRADE_RECORDS` of them.
+	///
+	/// Migrated records get zero timestamps and no expiry, DIDs, metadata or evidence, and DID
+	/// documents are indexed by their sender account. Verifiable credentials keep their
+	/// controller, are issued by the `Issuer` account and are indexed by their holder public key.
+	pub struct MigrateToV2<T, Issuer>(sp_std::marker::PhantomData<(T, Issuer)>);
+
+	impl<T: Config, Issuer: Get<T::AccountId>> OnRuntimeUpgrade for MigrateToV2<T, Issuer> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version != 1 {
+				frame_support::log::info!(
+					target: "runtime::verifiable",
+					"skipping v2 migration, on-chain storage version is {:?}",
+					on_chain_version
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut reads = 1u64;
+			let mut writes = 2u64;
+
+			DIDDocument::<T>::translate::<v1::DIDOf<T>, _>(|did_uri, did| {
+				reads += 1;
+				writes += 2;
+				DIDsByAccount::<T>::insert(&did.sender_account_id, &did_uri, ());
+				Some(DID {
+					signatures: did.signatures,
+					did_resolution_metadata: did.did_resolution_metadata,
+					did_document_metadata: did.did_document_metadata,
+					block_number: did.block_number,
+					updated_block_number: did.updated_block_number,
+					timestamp: 0,
+					updated_timestamp: 0,
+					did_ref: did.did_ref,
+					sender_account_id: did.sender_account_id,
+				})
+			});
+
+			let issuer = Issuer::get();
+			VerifiableCredential::<T>::translate::<v1::VerifiableCredentialMetadataOf<T>, _>(
+				|vc_fingerprint, vc| {
+					reads += 1;
+					writes += 2;
+					CredentialsByHolder::<T>::insert(
+						Pallet::<T>::holder_key(&vc.public_key),
+						&vc_fingerprint,
+						(),
+					);
+					Some(VerifiableCredentialMetadata {
+						account_id: vc.account_id,
+						public_key: vc.public_key,
+						block_number: vc.block_number,
+						updated_block_number: vc.updated_block_number,
+						timestamp: 0,
+						updated_timestamp: 0,
+						active: vc.active,
+						issuer: issuer.clone(),
+						expiry_block_number: None,
+						issuer_did: None,
+						subject_did: None,
+					})
+				},
+			);
+
+			TrailMigrationCursor::<T>::put(
+				v1::VerifiableCredentialTrail::<T>::final_prefix().to_vec(),
+			);
+			StorageVersion::new(2).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let dids = DIDDocument::<T>::iter_keys().count() as u32;
+			let credentials = VerifiableCredential::<T>::iter_keys().count() as u32;
+			ensure!(
+				dids.saturating_add(credentials) <= MAX_UPGRADE_RECORDS,
+				"too many records to migrate in the upgrade block"
+			);
+			Ok((dids, credentials).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let (dids, credentials) =
+				<(u32, u32)>::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+			ensure!(Pallet::<T>::on_chain_storage_version() == 2, "storage version not updated");
+			ensure!(
+				DIDDocument::<T>::iter_values().count() as u32 == dids,
+				"DID documents lost during migration"
+			);
+			ensure!(
+				VerifiableCredential::<T>::iter_values().count() as u32 == credentials,
+				"verifiable credentials lost during migration"
+			);
+			ensure!(TrailMigrationCursor::<T>::exists(), "trail migration not started");
+			Ok(())
+		}
+	}
+
+	/// Migrates version 1 trails into sequenced trail entries until `max_entries` entries were
+	/// written, resuming after the trail migrated last. Clears the cursor once no version 1 trail
+	/// is left.
+	pub fn migrate_trails<T: Config>(max_entries: u32) -> Weight {
+		let mut cursor = match TrailMigrationCursor::<T>::get() {
+			| Some(cursor) => cursor,
+			| None => return T::DbWeight::get().reads(1),
+		};
+		let prefix = v1::VerifiableCredentialTrail::<T>::final_prefix();
+		let mut reads = 1u64;
+		let mut writes = 1u64;
+		let mut entries = 0u32;
+
+		while entries < max_entries {
+			let key = match sp_io::storage::next_key(&cursor) {
+				| Some(key) if key.starts_with(&prefix) => key,
+				| _ => {
+					TrailMigrationCursor::<T>::kill();
+					frame_support::log::info!(
+						target: "runtime::verifiable",
+						"v2 trail migration completed"
+					);
+					return T::DbWeight::get().reads_writes(reads, writes)
+				},
+			};
+			reads += 1;
+			cursor = key;
+
+			// Trail entries extend the key of the version 1 trail of their fingerprint with the
+			// sequence number, so only keys that end with the fingerprint hold version 1 trails
+			let mut key_material = Blake2_128Concat::reverse(&cursor[prefix.len()..]);
+			let vc_fingerprint =
+				match BoundedVec::<u8, T::VCFingerPrintSize>::decode(&mut key_material) {
+					| Ok(vc_fingerprint) if key_material.is_empty() => vc_fingerprint,
+					| _ => continue,
+				};
+			let logs = match v1::VerifiableCredentialTrail::<T>::take(&vc_fingerprint) {
+				| Some(logs) => logs.into_inner(),
+				| None => continue,
+			};
+			writes += 1;
+
+			let last = match logs.last() {
+				| Some(log) => log.status.clone(),
+				| None => continue,
+			};
+			let head = logs.len() as u32;
+			for (sequence, log) in logs.into_iter().enumerate() {
+				let key = VerifiableCredentialTrail::<T>::hashed_key_for(
+					&vc_fingerprint,
+					sequence as u32,
+				);
+				VerifiableCredentialTrail::<T>::insert(
+					&vc_fingerprint,
+					sequence as u32,
+					VerifiableCredentialLog {
+						account_id: log.account_id,
+						status: log.status,
+						block_number: log.block_number,
+						timestamp: None,
+						metadata: None,
+						evidence_hash: None,
+						role: None,
+					},
+				);
+				// Resume after the entries just written rather than walking over them
+				cursor = cursor.max(key);
+			}
+			VerifiableCredentialTrailHead::<T>::insert(&vc_fingerprint, head);
+			VerifiableCredentialCurrentStatus::<T>::insert(&vc_fingerprint, last);
+			entries = entries.saturating_add(head);
+			writes += head as u64 + 2;
+		}
+
+		TrailMigrationCursor::<T>::put(cursor);
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	/// Whether the version 1 trail of a verifiable credential is still to be migrated
+	pub fn is_trail_pending<T: Config>(
+		vc_fingerprint: &BoundedVec<u8, T::VCFingerPrintSize>,
+	) -> bool {
+		TrailMigrationCursor::<T>::exists() &&
+			v1::VerifiableCredentialTrail::<T>::contains_key(vc_fingerprint)
+	}
+}
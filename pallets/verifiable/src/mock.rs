@@ -8,6 +8,7 @@ use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	transaction_validity::TransactionPriority,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -21,6 +22,8 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system,
+		Balances: pallet_balances,
+		Timestamp: pallet_timestamp,
 		Verifiable: verifiable,
 	}
 );
@@ -43,7 +46,7 @@ impl system::Config for Test {
 	type DbWeight = ();
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -52,12 +55,53 @@ impl system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<5>;
+	type WeightInfo = ();
+}
+
 parameter_types! {
 	pub const MetadataSize: u32 = 2048;
 	pub const MaxDIDsPerAccount: u32 = 10;
 	pub const PublicKeySize: u32 = 100;
-	pub const VCFingerPrintSize: u32 = 100;
+	pub const VCFingerPrintSize: u32 = 64;
+	pub const MaxCredentialContentSize: u32 = 1024;
 	pub const DIDURISize: u32 = 57;
+	pub const MaxLineageSize: u32 = 10;
+	pub const TrailMetadataSize: u32 = 64;
+	pub const DepositBase: u64 = 100;
+	pub const DepositPerByte: u64 = 1;
+	pub const CustomStatusLabelSize: u32 = 32;
+	pub const MaxCustomStatuses: u32 = 2;
+	pub const RoleNameSize: u32 = 16;
+	pub const MaxRoles: u32 = 2;
+	pub const MaxRoleStatuses: u32 = 3;
+	pub const TrailArchiveAge: u64 = 10;
+	pub const MaxArchiveEntries: u32 = 3;
+	pub const DeviceLabelSize: u32 = 16;
+	pub const MaxDeviceStatuses: u32 = 2;
+	pub const DeviceTracePeriod: u64 = 10;
+	pub const MaxDeviceTracesPerPeriod: u32 = 3;
+	pub const DeviceTracePriority: TransactionPriority = 100;
+	pub const FeelessTraceEra: u64 = 10;
+	pub const MaxFeelessTracesPerEra: u32 = 2;
+	pub const RateLimitWindow: u64 = 10;
+	pub const MaxOperationsPerWindow: u32 = 20;
+	pub const MaxIssuerTracesPerWindow: u32 = 30;
 }
 
 impl verifiable::Config for Test {
@@ -67,13 +111,51 @@ impl verifiable::Config for Test {
 	type MaxDIDsPerAccount = MaxDIDsPerAccount;
 	type PublicKeySize = PublicKeySize;
 	type VCFingerPrintSize = VCFingerPrintSize;
+	type FingerprintHasher = verifiable::Blake2b256;
+	type MaxCredentialContentSize = MaxCredentialContentSize;
 	type DIDURISize = DIDURISize;
+	type MaxLineageSize = MaxLineageSize;
+	type TrailMetadataSize = TrailMetadataSize;
+	type TimeProvider = Timestamp;
+	type Currency = Balances;
+	type DepositBase = DepositBase;
+	type DepositPerByte = DepositPerByte;
+	type CustomStatusLabelSize = CustomStatusLabelSize;
+	type MaxCustomStatuses = MaxCustomStatuses;
+	type RoleNameSize = RoleNameSize;
+	type MaxRoles = MaxRoles;
+	type MaxRoleStatuses = MaxRoleStatuses;
+	type TrailArchiveAge = TrailArchiveAge;
+	type MaxArchiveEntries = MaxArchiveEntries;
+	type DeviceLabelSize = DeviceLabelSize;
+	type MaxDeviceStatuses = MaxDeviceStatuses;
+	type DeviceTracePeriod = DeviceTracePeriod;
+	type MaxDeviceTracesPerPeriod = MaxDeviceTracesPerPeriod;
+	type DeviceTracePriority = DeviceTracePriority;
+	type FeelessTraceEra = FeelessTraceEra;
+	type MaxFeelessTracesPerEra = MaxFeelessTracesPerEra;
+	type RateLimitWindow = RateLimitWindow;
+	type MaxOperationsPerWindow = MaxOperationsPerWindow;
+	type MaxIssuerTracesPerWindow = MaxIssuerTracesPerWindow;
+	type RateLimitOrigin = frame_system::EnsureRoot<u64>;
 }
 pub const ALICE: u64 = 1;
 pub const BOB: u64 = 2;
 pub const CHARLIE: u64 = 3;
 
+pub const INITIAL_BALANCE: u64 = 1_000_000;
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	let mut storage = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![
+			(ALICE, INITIAL_BALANCE),
+			(BOB, INITIAL_BALANCE),
+			(CHARLIE, INITIAL_BALANCE),
+		],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	storage.into()
 }
@@ -1,4 +1,4 @@
-use super::{mock, mock::*};
+use super::{mock, mock::*, VerifiableCredentialMetadataPayload};
 use frame_support::{assert_ok, BoundedVec};
 use frame_system::RawOrigin;
 fn origin(account: u64) -> mock::RuntimeOrigin {
@@ -35,6 +35,44 @@ fn get_did_resolution_metadata() -> BoundedVec<u8, MetadataSize> {
 	did_resolution_metadata
 }
 
+fn get_vc_fingerprint(content: &str) -> BoundedVec<u8, VCFingerPrintSize> {
+	Verifiable::fingerprint_of(content.as_bytes()).try_into().unwrap()
+}
+
+fn get_vc_metadata_input(
+	account_id: u64,
+) -> VerifiableCredentialMetadataPayload<u64, u64, PublicKeySize, DIDURISize> {
+	VerifiableCredentialMetadataPayload {
+		account_id: Some(account_id),
+		public_key: get_public_key(vec![
+			0, 1, 217, 200, 51, 244, 152, 125, 173, 92, 30, 224, 60, 141, 221, 44, 65, 132, 45, 94,
+			199, 150, 116, 108, 95, 18, 118, 246, 86, 167, 64, 132, 76,
+		]),
+		active: Some(true),
+		expiry_block_number: None,
+		issuer_did: None,
+		subject_did: None,
+	}
+}
+
+fn create_credential_with(
+	issuer: u64,
+	content: &str,
+	vc_metadata_input: VerifiableCredentialMetadataPayload<u64, u64, PublicKeySize, DIDURISize>,
+) -> BoundedVec<u8, VCFingerPrintSize> {
+	let vc_fingerprint = get_vc_fingerprint(content);
+	assert_ok!(Verifiable::create_verifiable_credential(
+		origin(issuer),
+		vc_fingerprint.clone(),
+		vc_metadata_input
+	));
+	vc_fingerprint
+}
+
+fn create_credential(content: &str) -> BoundedVec<u8, VCFingerPrintSize> {
+	create_credential_with(ALICE, content, get_vc_metadata_input(ALICE))
+}
+
 fn get_did_uri() -> BoundedVec<u8, DIDURISize> {
 	let did_uri: BoundedVec<u8, DIDURISize> = "did:alto:0x1234567890123456789012345678901234567890"
 		.as_bytes()
@@ -220,8 +258,7 @@ mod create_verifiable_credential {
 	fn create_verifiable_credential() {
 		new_test_ext().execute_with(|| {
 			let alice: mock::RuntimeOrigin = origin(ALICE);
-			let vc_fingerprint: BoundedVec<u8, VCFingerPrintSize> =
-				"vc_fingerprint".as_bytes().to_vec().try_into().unwrap();
+			let vc_fingerprint = get_vc_fingerprint("vc_fingerprint");
 			let public_key: BoundedVec<u8, PublicKeySize> = vec![
 				0, 1, 217, 200, 51, 244, 152, 125, 173, 92, 30, 224, 60, 141, 221, 44, 65, 132, 45,
 				94, 199, 150, 116, 108, 95, 18, 118, 246, 86, 167, 64, 132, 76,
@@ -232,6 +269,9 @@ mod create_verifiable_credential {
 				account_id: Some(1),
 				public_key: public_key.clone(),
 				active: Some(true),
+				expiry_block_number: None,
+				issuer_did: None,
+				subject_did: None,
 			};
 			assert_ok!(Verifiable::create_verifiable_credential(
 				alice,
@@ -250,8 +290,7 @@ mod create_verifiable_credential {
 	fn create_existing_verifiable_credential() {
 		new_test_ext().execute_with(|| {
 			let alice: mock::RuntimeOrigin = origin(ALICE);
-			let vc_fingerprint: BoundedVec<u8, VCFingerPrintSize> =
-				"vc_fingerprint".as_bytes().to_vec().try_into().unwrap();
+			let vc_fingerprint = get_vc_fingerprint("vc_fingerprint");
 			let public_key: BoundedVec<u8, PublicKeySize> = vec![
 				0, 1, 217, 200, 51, 244, 152, 125, 173, 92, 30, 224, 60, 141, 221, 44, 65, 132, 45,
 				94, 199, 150, 116, 108, 95, 18, 118, 246, 86, 167, 64, 132, 76,
@@ -262,6 +301,9 @@ mod create_verifiable_credential {
 				account_id: Some(1),
 				public_key,
 				active: Some(true),
+				expiry_block_number: None,
+				issuer_did: None,
+				subject_did: None,
 			};
 			assert_ok!(Verifiable::create_verifiable_credential(
 				alice.clone(),
@@ -279,14 +321,16 @@ mod create_verifiable_credential {
 	fn invalid_public_key() {
 		new_test_ext().execute_with(|| {
 			let alice: mock::RuntimeOrigin = origin(ALICE);
-			let vc_fingerprint: BoundedVec<u8, VCFingerPrintSize> =
-				"vc_fingerprint".as_bytes().to_vec().try_into().unwrap();
+			let vc_fingerprint = get_vc_fingerprint("vc_fingerprint");
 
 			let public_key: BoundedVec<u8, PublicKeySize> = vec![255, 32].try_into().unwrap();
 			let vc_metadata_input = VerifiableCredentialMetadataPayload {
 				account_id: Some(1),
 				public_key,
 				active: Some(true),
+				expiry_block_number: None,
+				issuer_did: None,
+				subject_did: None,
 			};
 
 			assert_noop!(
@@ -299,15 +343,18 @@ mod create_verifiable_credential {
 
 mod update_verifiable_credential {
 	use super::*;
-	use crate::{Error, VerifiableCredential, VerifiableCredentialMetadataPayload};
+	use crate::{
+		CredentialDeposits, Error, Event, VerifiableCredential, VerifiableCredentialMetadataPayload,
+	};
 	use frame_support::assert_noop;
+	use sp_runtime::traits::{BlakeTwo256, Hash};
 
 	#[test]
 	fn update_verifiable_credential() {
 		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
 			let alice: mock::RuntimeOrigin = origin(ALICE);
-			let vc_fingerprint: BoundedVec<u8, VCFingerPrintSize> =
-				"vc_fingerprint".as_bytes().to_vec().try_into().unwrap();
+			let vc_fingerprint = get_vc_fingerprint("vc_fingerprint");
 			let public_key = get_public_key(vec![
 				0, 1, 217, 200, 51, 244, 152, 125, 173, 92, 30, 224, 60, 141, 221, 44, 65, 132, 45,
 				94, 199, 150, 116, 108, 95, 18, 118, 246, 86, 167, 64, 132, 76,
@@ -316,6 +363,9 @@ mod update_verifiable_credential {
 				account_id: Some(1),
 				public_key,
 				active: Some(true),
+				expiry_block_number: None,
+				issuer_did: None,
+				subject_did: None,
 			};
 			assert_ok!(Verifiable::create_verifiable_credential(
 				alice.clone(),
@@ -331,6 +381,9 @@ mod update_verifiable_credential {
 				account_id: Some(2),
 				public_key: public_key.clone(),
 				active: Some(false),
+				expiry_block_number: None,
+				issuer_did: None,
+				subject_did: None,
 			};
 			assert_ok!(Verifiable::update_verifiable_credential(
 				alice,
@@ -343,6 +396,18 @@ mod update_verifiable_credential {
 			assert_eq!(result.account_id.unwrap(), 2);
 			assert_eq!(result.active.unwrap(), false);
 			assert_eq!(result.public_key, public_key);
+			System::assert_last_event(
+				Event::VerifiableCredentialFingerPrintUpdated {
+					vc_fingerprint,
+					account_id: ALICE,
+					updated_block_number: 1,
+					issuer: ALICE,
+					metadata_hash: BlakeTwo256::hash_of(&result),
+					previous_active: Some(true),
+					active: Some(false),
+				}
+				.into(),
+			);
 		});
 	}
 
@@ -350,8 +415,7 @@ mod update_verifiable_credential {
 	fn update_non_existing_verifiable_credential() {
 		new_test_ext().execute_with(|| {
 			let alice: mock::RuntimeOrigin = origin(ALICE);
-			let vc_fingerprint: BoundedVec<u8, VCFingerPrintSize> =
-				"vc_fingerprint".as_bytes().to_vec().try_into().unwrap();
+			let vc_fingerprint = get_vc_fingerprint("vc_fingerprint");
 			let public_key = get_public_key(vec![
 				0, 1, 217, 200, 51, 244, 152, 125, 173, 92, 30, 224, 60, 141, 221, 44, 65, 132, 45,
 				94, 199, 150, 116, 108, 95, 18, 118, 246, 86, 167, 64, 132, 76,
@@ -360,6 +424,9 @@ mod update_verifiable_credential {
 				account_id: Some(1),
 				public_key,
 				active: Some(true),
+				expiry_block_number: None,
+				issuer_did: None,
+				subject_did: None,
 			};
 
 			assert_noop!(
@@ -368,6 +435,104 @@ mod update_verifiable_credential {
 			);
 		});
 	}
+
+	#[test]
+	fn update_by_non_issuer() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = get_vc_fingerprint("vc_fingerprint");
+			let vc_metadata_input = VerifiableCredentialMetadataPayload {
+				account_id: Some(BOB),
+				public_key: get_public_key(vec![0, 1, 2, 3, 4, 5, 6, 7]),
+				active: Some(true),
+				expiry_block_number: None,
+				issuer_did: None,
+				subject_did: None,
+			};
+			assert_ok!(Verifiable::create_verifiable_credential(
+				origin(ALICE),
+				vc_fingerprint.clone(),
+				vc_metadata_input.clone()
+			));
+
+			assert_noop!(
+				Verifiable::update_verifiable_credential(
+					origin(CHARLIE),
+					vc_fingerprint.clone(),
+					vc_metadata_input.clone()
+				),
+				Error::<Test>::NotVerifiableCredentialIssuer
+			);
+			assert_ok!(Verifiable::update_verifiable_credential(
+				origin(BOB),
+				vc_fingerprint,
+				vc_metadata_input
+			));
+		});
+	}
+
+	#[test]
+	fn update_by_controller() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = get_vc_fingerprint("vc_fingerprint");
+			let vc_metadata_input = VerifiableCredentialMetadataPayload {
+				account_id: Some(BOB),
+				public_key: get_public_key(vec![0, 1, 2, 3, 4, 5, 6, 7]),
+				active: Some(true),
+				expiry_block_number: None,
+				issuer_did: None,
+				subject_did: None,
+			};
+			assert_ok!(Verifiable::create_verifiable_credential(
+				origin(ALICE),
+				vc_fingerprint.clone(),
+				vc_metadata_input.clone()
+			));
+
+			for vc_metadata_input in [
+				VerifiableCredentialMetadataPayload {
+					active: Some(false),
+					..vc_metadata_input.clone()
+				},
+				VerifiableCredentialMetadataPayload {
+					expiry_block_number: Some(10),
+					..vc_metadata_input.clone()
+				},
+			] {
+				assert_noop!(
+					Verifiable::update_verifiable_credential(
+						origin(BOB),
+						vc_fingerprint.clone(),
+						vc_metadata_input
+					),
+					Error::<Test>::NotVerifiableCredentialIssuer
+				);
+			}
+			assert_noop!(
+				Verifiable::update_verifiable_credential(
+					origin(BOB),
+					vc_fingerprint.clone(),
+					VerifiableCredentialMetadataPayload {
+						public_key: get_public_key(vec![0, 1, 2]),
+						..vc_metadata_input.clone()
+					}
+				),
+				Error::<Test>::InvalidPublicKey
+			);
+
+			let reserved = Balances::reserved_balance(ALICE);
+			assert_ok!(Verifiable::update_verifiable_credential(
+				origin(BOB),
+				vc_fingerprint.clone(),
+				VerifiableCredentialMetadataPayload {
+					public_key: get_public_key(vec![7, 6, 5, 4, 3, 2, 1, 0]),
+					..vc_metadata_input
+				}
+			));
+			assert_eq!(CredentialDeposits::<Test>::get(&vc_fingerprint).unwrap().depositor, ALICE);
+			assert_eq!(Balances::reserved_balance(ALICE), reserved);
+			assert_eq!(Balances::reserved_balance(BOB), 0);
+		});
+	}
 }
 
 mod revoke_verifiable_credential {
@@ -379,8 +544,7 @@ mod revoke_verifiable_credential {
 	fn revoke_verifiable_credential() {
 		new_test_ext().execute_with(|| {
 			let alice: mock::RuntimeOrigin = origin(ALICE);
-			let vc_fingerprint: BoundedVec<u8, VCFingerPrintSize> =
-				"vc_fingerprint".as_bytes().to_vec().try_into().unwrap();
+			let vc_fingerprint = get_vc_fingerprint("vc_fingerprint");
 			let public_key = get_public_key(vec![
 				0, 1, 217, 200, 51, 244, 152, 125, 173, 92, 30, 224, 60, 141, 221, 44, 65, 132, 45,
 				94, 199, 150, 116, 108, 95, 18, 118, 246, 86, 167, 64, 132, 76,
@@ -389,6 +553,9 @@ mod revoke_verifiable_credential {
 				account_id: Some(1),
 				public_key,
 				active: Some(true),
+				expiry_block_number: None,
+				issuer_did: None,
+				subject_did: None,
 			};
 			assert_ok!(Verifiable::create_verifiable_credential(
 				alice.clone(),
@@ -403,12 +570,37 @@ mod revoke_verifiable_credential {
 		});
 	}
 
+	#[test]
+	fn revoke_by_non_issuer() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = get_vc_fingerprint("vc_fingerprint");
+			let vc_metadata_input = VerifiableCredentialMetadataPayload {
+				account_id: Some(ALICE),
+				public_key: get_public_key(vec![0, 1, 2, 3, 4, 5, 6, 7]),
+				active: Some(true),
+				expiry_block_number: None,
+				issuer_did: None,
+				subject_did: None,
+			};
+			assert_ok!(Verifiable::create_verifiable_credential(
+				origin(ALICE),
+				vc_fingerprint.clone(),
+				vc_metadata_input
+			));
+
+			assert_noop!(
+				Verifiable::revoke_verifiable_credential(origin(BOB), vc_fingerprint.clone()),
+				Error::<Test>::NotVerifiableCredentialIssuer
+			);
+			assert!(VerifiableCredential::<Test>::get(&vc_fingerprint).is_some());
+		});
+	}
+
 	#[test]
 	fn revoke_non_exising_verifiable_credential() {
 		new_test_ext().execute_with(|| {
 			let alice: mock::RuntimeOrigin = origin(ALICE);
-			let vc_fingerprint: BoundedVec<u8, VCFingerPrintSize> =
-				"vc_fingerprint".as_bytes().to_vec().try_into().unwrap();
+			let vc_fingerprint = get_vc_fingerprint("vc_fingerprint");
 
 			assert_noop!(
 				Verifiable::revoke_verifiable_credential(alice, vc_fingerprint),
@@ -422,16 +614,18 @@ mod trace_credential {
 
 	use super::*;
 	use crate::{
-		pallet::VerifiableCredentialTrail, VerifiableCredential, VerifiableCredentialLog,
-		VerifiableCredentialMetadataPayload, VerifiableCredentialStatus,
+		Error, Event, VerifiableCredential, VerifiableCredentialCurrentStatus,
+		VerifiableCredentialLog, VerifiableCredentialMetadataPayload, VerifiableCredentialStatus,
+		VerifiableCredentialTrailHead,
 	};
+	use frame_support::assert_noop;
+	use sp_runtime::traits::{BlakeTwo256, Hash};
 
 	#[test]
 	fn trace_credential() {
 		new_test_ext().execute_with(|| {
 			let alice: mock::RuntimeOrigin = origin(ALICE);
-			let vc_fingerprint: BoundedVec<u8, VCFingerPrintSize> =
-				"vc_fingerprint".as_bytes().to_vec().try_into().unwrap();
+			let vc_fingerprint = get_vc_fingerprint("vc_fingerprint");
 			let public_key = get_public_key(vec![
 				0, 1, 217, 200, 51, 244, 152, 125, 173, 92, 30, 224, 60, 141, 221, 44, 65, 132, 45,
 				94, 199, 150, 116, 108, 95, 18, 118, 246, 86, 167, 64, 132, 76,
@@ -440,6 +634,9 @@ mod trace_credential {
 				account_id: Some(1),
 				public_key,
 				active: Some(true),
+				expiry_block_number: None,
+				issuer_did: None,
+				subject_did: None,
 			};
 			assert_ok!(Verifiable::create_verifiable_credential(
 				alice.clone(),
@@ -452,10 +649,12 @@ mod trace_credential {
 				alice.clone(),
 				Some(BOB),
 				vc_fingerprint.clone(),
-				VerifiableCredentialStatus::Created
+				VerifiableCredentialStatus::Created,
+				None,
+				None
 			));
 
-			let result = VerifiableCredentialTrail::<Test>::get(&vc_fingerprint).unwrap();
+			let result = Verifiable::verifiable_credential_trail(&vc_fingerprint, 0, u32::MAX);
 			assert_eq!(result.len(), 1);
 			assert_eq!(result[0].account_id, Some(BOB));
 			assert_eq!(result[0].status, VerifiableCredentialStatus::Created);
@@ -464,9 +663,11 @@ mod trace_credential {
 				alice,
 				Some(CHARLIE),
 				vc_fingerprint.clone(),
-				VerifiableCredentialStatus::Scanned
+				VerifiableCredentialStatus::Scanned,
+				None,
+				None
 			));
-			let result = VerifiableCredentialTrail::<Test>::get(&vc_fingerprint).unwrap();
+			let result = Verifiable::verifiable_credential_trail(&vc_fingerprint, 0, u32::MAX);
 			assert_eq!(result.len(), 2);
 			assert_eq!(result[1].account_id, Some(CHARLIE));
 			assert_eq!(result[1].status, VerifiableCredentialStatus::Scanned);
@@ -478,14 +679,2870 @@ mod trace_credential {
 						account_id: Some(BOB),
 						status: VerifiableCredentialStatus::Created,
 						block_number: Some(0),
+						timestamp: Some(0),
+						metadata: None,
+						evidence_hash: None,
+						role: None,
 					},
 					VerifiableCredentialLog {
 						account_id: Some(CHARLIE),
 						status: VerifiableCredentialStatus::Scanned,
 						block_number: Some(0),
+						timestamp: Some(0),
+						metadata: None,
+						evidence_hash: None,
+						role: None,
 					},
 				]
 			);
 		});
 	}
+
+	fn trace(
+		vc_fingerprint: &BoundedVec<u8, VCFingerPrintSize>,
+		status: VerifiableCredentialStatus,
+	) -> frame_support::dispatch::DispatchResultWithPostInfo {
+		Verifiable::trace_credential(
+			origin(ALICE),
+			Some(BOB),
+			vc_fingerprint.clone(),
+			status,
+			None,
+			None,
+		)
+	}
+
+	#[test]
+	fn status_transitions() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_credential("vc_fingerprint");
+
+			assert_ok!(trace(&vc_fingerprint, VerifiableCredentialStatus::Pending));
+			assert_ok!(trace(&vc_fingerprint, VerifiableCredentialStatus::Created));
+			assert_ok!(trace(&vc_fingerprint, VerifiableCredentialStatus::Scanned));
+			assert_ok!(trace(&vc_fingerprint, VerifiableCredentialStatus::Suspended));
+			assert_ok!(trace(&vc_fingerprint, VerifiableCredentialStatus::Resumed));
+			assert_ok!(trace(&vc_fingerprint, VerifiableCredentialStatus::Scanned));
+
+			assert_eq!(
+				VerifiableCredentialCurrentStatus::<Test>::get(&vc_fingerprint),
+				Some(VerifiableCredentialStatus::Scanned)
+			);
+			let result = Verifiable::verifiable_credential_trail(&vc_fingerprint, 0, u32::MAX);
+			assert_eq!(result[0].status, VerifiableCredentialStatus::Pending);
+			assert_eq!(result.len(), 6);
+		});
+	}
+
+	#[test]
+	fn paged_trail() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_credential("vc_fingerprint");
+
+			assert_ok!(trace(&vc_fingerprint, VerifiableCredentialStatus::Created));
+			for _ in 0..4 {
+				assert_ok!(trace(&vc_fingerprint, VerifiableCredentialStatus::Scanned));
+			}
+			assert_eq!(VerifiableCredentialTrailHead::<Test>::get(&vc_fingerprint), 5);
+
+			let page = Verifiable::verifiable_credential_trail(&vc_fingerprint, 0, 2);
+			assert_eq!(page.len(), 2);
+			assert_eq!(page[0].status, VerifiableCredentialStatus::Created);
+
+			let page = Verifiable::verifiable_credential_trail(&vc_fingerprint, 4, 2);
+			assert_eq!(page.len(), 1);
+			assert_eq!(page[0].status, VerifiableCredentialStatus::Scanned);
+
+			assert!(Verifiable::verifiable_credential_trail(&vc_fingerprint, 5, 2).is_empty());
+		});
+	}
+
+	#[test]
+	fn invalid_initial_status() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_credential("vc_fingerprint");
+			assert_noop!(
+				trace(&vc_fingerprint, VerifiableCredentialStatus::Scanned),
+				Error::<Test>::InvalidInitialStatus
+			);
+		});
+	}
+
+	#[test]
+	fn resume_without_suspend() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_credential("vc_fingerprint");
+			assert_ok!(trace(&vc_fingerprint, VerifiableCredentialStatus::Created));
+			assert_noop!(
+				trace(&vc_fingerprint, VerifiableCredentialStatus::Resumed),
+				Error::<Test>::ResumeWithoutSuspend
+			);
+		});
+	}
+
+	#[test]
+	fn nothing_after_frozen() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_credential("vc_fingerprint");
+			assert_ok!(trace(&vc_fingerprint, VerifiableCredentialStatus::Created));
+			assert_ok!(trace(&vc_fingerprint, VerifiableCredentialStatus::Frozen));
+			assert_noop!(
+				trace(&vc_fingerprint, VerifiableCredentialStatus::Scanned),
+				Error::<Test>::VerifiableCredentialFrozen
+			);
+		});
+	}
+
+	#[test]
+	fn lineage_statuses_reserved() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_credential("vc_fingerprint");
+			assert_ok!(trace(&vc_fingerprint, VerifiableCredentialStatus::Created));
+			for status in [
+				VerifiableCredentialStatus::Migrated,
+				VerifiableCredentialStatus::Split,
+				VerifiableCredentialStatus::Merged,
+			] {
+				assert_noop!(trace(&vc_fingerprint, status), Error::<Test>::LineageStatusReserved);
+			}
+		});
+	}
+
+	#[test]
+	fn nothing_after_merged() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_credential("vc_fingerprint");
+			assert_ok!(trace(&vc_fingerprint, VerifiableCredentialStatus::Created));
+			assert_ok!(Verifiable::merge_credentials(
+				origin(ALICE),
+				vec![vc_fingerprint.clone()].try_into().unwrap(),
+				get_vc_fingerprint("merged")
+			));
+			assert_noop!(
+				trace(&vc_fingerprint, VerifiableCredentialStatus::Scanned),
+				Error::<Test>::VerifiableCredentialTerminated
+			);
+		});
+	}
+
+	#[test]
+	fn suspended_must_resume() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_credential("vc_fingerprint");
+			assert_ok!(trace(&vc_fingerprint, VerifiableCredentialStatus::Created));
+			assert_ok!(trace(&vc_fingerprint, VerifiableCredentialStatus::Suspended));
+			assert_noop!(
+				trace(&vc_fingerprint, VerifiableCredentialStatus::Scanned),
+				Error::<Test>::InvalidStatusTransition
+			);
+		});
+	}
+
+	#[test]
+	fn trace_with_evidence() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let vc_fingerprint = create_credential("vc_fingerprint");
+			let metadata: BoundedVec<u8, TrailMetadataSize> =
+				"loc:LK-CMB;temp:4C".as_bytes().to_vec().try_into().unwrap();
+			let evidence_hash = BlakeTwo256::hash("cold chain report".as_bytes());
+			assert_ok!(Verifiable::trace_credential(
+				origin(ALICE),
+				Some(BOB),
+				vc_fingerprint.clone(),
+				VerifiableCredentialStatus::Created,
+				Some(metadata.clone()),
+				Some(evidence_hash)
+			));
+
+			let result = Verifiable::verifiable_credential_trail(&vc_fingerprint, 0, 1);
+			assert_eq!(result[0].metadata, Some(metadata));
+			assert_eq!(result[0].evidence_hash, Some(evidence_hash));
+			System::assert_last_event(
+				Event::VerifiableCredentialEvent {
+					vc_fingerprint,
+					origin: ALICE,
+					block_number: 1,
+					status: VerifiableCredentialStatus::Created,
+					evidence_hash: Some(evidence_hash),
+					account_id: Some(BOB),
+					issuer: ALICE,
+					sequence: 0,
+					previous_status: None,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn trace_event_carries_sequence_and_previous_status() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let vc_fingerprint = create_credential("vc_fingerprint");
+			assert_ok!(trace(&vc_fingerprint, VerifiableCredentialStatus::Created));
+			assert_ok!(Verifiable::trace_credential(
+				origin(CHARLIE),
+				None,
+				vc_fingerprint.clone(),
+				VerifiableCredentialStatus::Scanned,
+				None,
+				None
+			));
+			System::assert_last_event(
+				Event::VerifiableCredentialEvent {
+					vc_fingerprint,
+					origin: CHARLIE,
+					block_number: 1,
+					status: VerifiableCredentialStatus::Scanned,
+					evidence_hash: None,
+					account_id: None,
+					issuer: ALICE,
+					sequence: 1,
+					previous_status: Some(VerifiableCredentialStatus::Created),
+				}
+				.into(),
+			);
+		});
+	}
+}
+
+mod delete_verifiable_credential {
+	use super::*;
+	use crate::{
+		Error, RevokedVerifiableCredential, VerifiableCredential,
+		VerifiableCredentialMetadataPayload, VerifiableCredentialStatus,
+		VerifiableCredentialTombstone, VerifiableCredentialTrailHead,
+	};
+	use frame_support::assert_noop;
+
+	fn create_verifiable_credential(
+		expiry_block_number: Option<u64>,
+	) -> BoundedVec<u8, VCFingerPrintSize> {
+		let vc_fingerprint = create_credential_with(
+			ALICE,
+			"vc_fingerprint",
+			VerifiableCredentialMetadataPayload {
+				expiry_block_number,
+				..get_vc_metadata_input(ALICE)
+			},
+		);
+		assert_ok!(Verifiable::trace_credential(
+			origin(ALICE),
+			Some(BOB),
+			vc_fingerprint.clone(),
+			VerifiableCredentialStatus::Created,
+			None,
+			None
+		));
+		vc_fingerprint
+	}
+
+	#[test]
+	fn delete_revoked_verifiable_credential() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_verifiable_credential(None);
+			assert_ok!(Verifiable::revoke_verifiable_credential(
+				origin(ALICE),
+				vc_fingerprint.clone()
+			));
+			assert!(RevokedVerifiableCredential::<Test>::get(&vc_fingerprint).is_some());
+
+			assert_ok!(Verifiable::delete_verifiable_credential(
+				origin(ALICE),
+				vc_fingerprint.clone(),
+				10
+			));
+
+			assert!(VerifiableCredential::<Test>::get(&vc_fingerprint).is_none());
+			assert!(RevokedVerifiableCredential::<Test>::get(&vc_fingerprint).is_none());
+			assert!(
+				Verifiable::verifiable_credential_trail(&vc_fingerprint, 0, u32::MAX).is_empty()
+			);
+			assert_eq!(VerifiableCredentialTrailHead::<Test>::get(&vc_fingerprint), 0);
+			assert!(VerifiableCredentialTombstone::<Test>::contains_key(
+				Verifiable::tombstone_key(&vc_fingerprint)
+			));
+		});
+	}
+
+	#[test]
+	fn delete_expired_verifiable_credential() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_verifiable_credential(Some(5));
+			System::set_block_number(5);
+
+			assert_ok!(Verifiable::delete_verifiable_credential(
+				origin(ALICE),
+				vc_fingerprint.clone(),
+				10
+			));
+			assert!(VerifiableCredential::<Test>::get(&vc_fingerprint).is_none());
+			assert!(
+				Verifiable::verifiable_credential_trail(&vc_fingerprint, 0, u32::MAX).is_empty()
+			);
+			assert_eq!(VerifiableCredentialTrailHead::<Test>::get(&vc_fingerprint), 0);
+		});
+	}
+
+	#[test]
+	fn delete_valid_verifiable_credential() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_verifiable_credential(Some(5));
+			System::set_block_number(4);
+
+			assert_noop!(
+				Verifiable::delete_verifiable_credential(origin(ALICE), vc_fingerprint, 10),
+				Error::<Test>::VerifiableCredentialNotRevokedOrExpired
+			);
+		});
+	}
+
+	#[test]
+	fn delete_by_non_issuer() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_verifiable_credential(None);
+			assert_ok!(Verifiable::revoke_verifiable_credential(
+				origin(ALICE),
+				vc_fingerprint.clone()
+			));
+
+			assert_noop!(
+				Verifiable::delete_verifiable_credential(origin(BOB), vc_fingerprint, 10),
+				Error::<Test>::NotVerifiableCredentialIssuer
+			);
+		});
+	}
+
+	#[test]
+	fn recreate_deleted_verifiable_credential() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_verifiable_credential(None);
+			assert_ok!(Verifiable::revoke_verifiable_credential(
+				origin(ALICE),
+				vc_fingerprint.clone()
+			));
+			assert_ok!(Verifiable::delete_verifiable_credential(
+				origin(ALICE),
+				vc_fingerprint.clone(),
+				10
+			));
+
+			assert_noop!(
+				Verifiable::create_verifiable_credential(
+					origin(ALICE),
+					vc_fingerprint,
+					get_vc_metadata_input(ALICE)
+				),
+				Error::<Test>::VerifiableCredentialFingerPrintDeleted
+			);
+		});
+	}
+}
+
+mod credential_batch {
+	use super::*;
+	use crate::{BatchCredentialStatus, CredentialBatch, Error, RevokedBatchCredential};
+	use frame_support::assert_noop;
+	use sp_core::H256;
+
+	const FINGERPRINTS: [&str; 4] = ["diploma_0", "diploma_1", "diploma_2", "diploma_3"];
+
+	// Returns the merkle root and the proof of the first fingerprint
+	fn build_merkle_tree() -> (H256, Vec<H256>) {
+		let leaves: Vec<H256> = FINGERPRINTS
+			.iter()
+			.map(|fp| Verifiable::batch_leaf(&get_vc_fingerprint(fp)))
+			.collect();
+		let left = Verifiable::merkle_root(leaves[0], &[leaves[1]]);
+		let right = Verifiable::merkle_root(leaves[2], &[leaves[3]]);
+		(Verifiable::merkle_root(left, &[right]), vec![leaves[1], right])
+	}
+
+	#[test]
+	fn anchor_credential_batch() {
+		new_test_ext().execute_with(|| {
+			let (merkle_root, proof) = build_merkle_tree();
+			assert_ok!(Verifiable::anchor_credential_batch(origin(ALICE), merkle_root, 4));
+
+			let batch = CredentialBatch::<Test>::get(0).unwrap();
+			assert_eq!(batch.merkle_root, merkle_root);
+			assert_eq!(batch.issuer, ALICE);
+			assert_eq!(batch.batch_size, 4);
+
+			assert_eq!(
+				Verifiable::verify_batch_credential(
+					0,
+					&get_vc_fingerprint(FINGERPRINTS[0]),
+					&proof
+				),
+				Some(BatchCredentialStatus::Valid)
+			);
+			assert_eq!(
+				Verifiable::verify_batch_credential(
+					0,
+					&get_vc_fingerprint(FINGERPRINTS[1]),
+					&proof
+				),
+				Some(BatchCredentialStatus::InvalidProof)
+			);
+			assert_eq!(
+				Verifiable::verify_batch_credential(
+					1,
+					&get_vc_fingerprint(FINGERPRINTS[0]),
+					&proof
+				),
+				None
+			);
+		});
+	}
+
+	#[test]
+	fn anchor_empty_credential_batch() {
+		new_test_ext().execute_with(|| {
+			let (merkle_root, _) = build_merkle_tree();
+			assert_noop!(
+				Verifiable::anchor_credential_batch(origin(ALICE), merkle_root, 0),
+				Error::<Test>::EmptyCredentialBatch
+			);
+		});
+	}
+
+	#[test]
+	fn revoke_credential_batch() {
+		new_test_ext().execute_with(|| {
+			let (merkle_root, proof) = build_merkle_tree();
+			assert_ok!(Verifiable::anchor_credential_batch(origin(ALICE), merkle_root, 4));
+
+			assert_noop!(
+				Verifiable::revoke_credential_batch(origin(BOB), 0),
+				Error::<Test>::NotCredentialBatchIssuer
+			);
+			assert_ok!(Verifiable::revoke_credential_batch(origin(ALICE), 0));
+			assert_noop!(
+				Verifiable::revoke_credential_batch(origin(ALICE), 0),
+				Error::<Test>::CredentialBatchRevoked
+			);
+			assert_eq!(
+				Verifiable::verify_batch_credential(
+					0,
+					&get_vc_fingerprint(FINGERPRINTS[0]),
+					&proof
+				),
+				Some(BatchCredentialStatus::Revoked)
+			);
+		});
+	}
+
+	#[test]
+	fn revoke_batch_credential() {
+		new_test_ext().execute_with(|| {
+			let (merkle_root, proof) = build_merkle_tree();
+			assert_ok!(Verifiable::anchor_credential_batch(origin(ALICE), merkle_root, 4));
+
+			let vc_fingerprint = get_vc_fingerprint(FINGERPRINTS[0]);
+			assert_ok!(Verifiable::revoke_batch_credential(
+				origin(ALICE),
+				0,
+				vc_fingerprint.clone()
+			));
+			assert!(RevokedBatchCredential::<Test>::contains_key(
+				0,
+				Verifiable::batch_leaf(&vc_fingerprint)
+			));
+			assert_noop!(
+				Verifiable::revoke_batch_credential(origin(ALICE), 0, vc_fingerprint),
+				Error::<Test>::CredentialBatchRevoked
+			);
+
+			assert_eq!(
+				Verifiable::verify_batch_credential(
+					0,
+					&get_vc_fingerprint(FINGERPRINTS[0]),
+					&proof
+				),
+				Some(BatchCredentialStatus::Revoked)
+			);
+		});
+	}
+}
+
+mod credential_dids {
+	use super::*;
+	use crate::{
+		CredentialsByIssuerDID, DIDMetadataPayload, Error, VerifiableCredentialMetadataPayload,
+	};
+	use frame_support::assert_noop;
+
+	fn create_did(did_uri: &str) -> BoundedVec<u8, DIDURISize> {
+		let did_uri: BoundedVec<u8, DIDURISize> = did_uri.as_bytes().to_vec().try_into().unwrap();
+		let did = DIDMetadataPayload {
+			signatures: BoundedVec::default(),
+			did_resolution_metadata: Some(get_did_resolution_metadata()),
+			did_document_metadata: Some(get_did_document_metadata()),
+			did_ref: None,
+			sender_account_id: 1,
+		};
+		assert_ok!(Verifiable::create_did(origin(ALICE), did_uri.clone(), did));
+		did_uri
+	}
+
+	fn vc_metadata_input(
+		issuer_did: Option<BoundedVec<u8, DIDURISize>>,
+		subject_did: Option<BoundedVec<u8, DIDURISize>>,
+	) -> VerifiableCredentialMetadataPayload<u64, u64, PublicKeySize, DIDURISize> {
+		VerifiableCredentialMetadataPayload {
+			issuer_did,
+			subject_did,
+			..get_vc_metadata_input(ALICE)
+		}
+	}
+
+	#[test]
+	fn credentials_by_issuer_and_subject_did() {
+		new_test_ext().execute_with(|| {
+			let issuer_did = create_did("did:alto:issuer");
+			let subject_did = create_did("did:alto:subject");
+
+			let mut fingerprints: Vec<Vec<u8>> = Vec::new();
+			for fp in ["vc_0", "vc_1", "vc_2"] {
+				let vc_fingerprint = get_vc_fingerprint(fp);
+				assert_ok!(Verifiable::create_verifiable_credential(
+					origin(ALICE),
+					vc_fingerprint.clone(),
+					vc_metadata_input(Some(issuer_did.clone()), Some(subject_did.clone()))
+				));
+				fingerprints.push(vc_fingerprint.into_inner());
+			}
+
+			let first_page = Verifiable::credentials_by_issuer_did(&issuer_did, None, 2);
+			assert_eq!(first_page.len(), 2);
+			let second_page =
+				Verifiable::credentials_by_issuer_did(&issuer_did, first_page.last().cloned(), 2);
+			assert_eq!(second_page.len(), 1);
+
+			let mut issued: Vec<Vec<u8>> = first_page.into_iter().chain(second_page).collect();
+			issued.sort();
+			fingerprints.sort();
+			assert_eq!(issued, fingerprints);
+
+			assert_eq!(Verifiable::credentials_by_subject_did(&subject_did, None, 10).len(), 3);
+			assert!(Verifiable::credentials_by_subject_did(&issuer_did, None, 10).is_empty());
+		});
+	}
+
+	#[test]
+	fn revoke_removes_did_indexes() {
+		new_test_ext().execute_with(|| {
+			let issuer_did = create_did("did:alto:issuer");
+			let vc_fingerprint = get_vc_fingerprint("vc_fingerprint");
+			assert_ok!(Verifiable::create_verifiable_credential(
+				origin(ALICE),
+				vc_fingerprint.clone(),
+				vc_metadata_input(Some(issuer_did.clone()), None)
+			));
+			assert!(CredentialsByIssuerDID::<Test>::contains_key(&issuer_did, &vc_fingerprint));
+
+			assert_ok!(Verifiable::revoke_verifiable_credential(
+				origin(ALICE),
+				vc_fingerprint.clone()
+			));
+			assert!(!CredentialsByIssuerDID::<Test>::contains_key(&issuer_did, &vc_fingerprint));
+		});
+	}
+
+	#[test]
+	fn create_with_non_existing_did() {
+		new_test_ext().execute_with(|| {
+			let subject_did: BoundedVec<u8, DIDURISize> =
+				"did:alto:unknown".as_bytes().to_vec().try_into().unwrap();
+			let vc_fingerprint = get_vc_fingerprint("vc_fingerprint");
+			assert_noop!(
+				Verifiable::create_verifiable_credential(
+					origin(ALICE),
+					vc_fingerprint,
+					vc_metadata_input(None, Some(subject_did))
+				),
+				Error::<Test>::DIDDoesNotExist
+			);
+		});
+	}
+}
+
+mod credentials_by_holder {
+	use super::*;
+	use crate::{
+		CredentialsByHolder, VerifiableCredentialMetadataPayload, VerifiableCredentialStatus,
+	};
+
+	fn fingerprint(fp: &str) -> BoundedVec<u8, VCFingerPrintSize> {
+		get_vc_fingerprint(fp)
+	}
+
+	fn holder_key(seed: u8) -> BoundedVec<u8, PublicKeySize> {
+		get_public_key(vec![seed; 32])
+	}
+
+	fn vc_metadata_input(
+		public_key: BoundedVec<u8, PublicKeySize>,
+	) -> VerifiableCredentialMetadataPayload<u64, u64, PublicKeySize, DIDURISize> {
+		VerifiableCredentialMetadataPayload { public_key, ..get_vc_metadata_input(ALICE) }
+	}
+
+	fn create_held_credential(fp: &str, public_key: BoundedVec<u8, PublicKeySize>) {
+		create_credential_with(ALICE, fp, vc_metadata_input(public_key));
+	}
+
+	#[test]
+	fn credentials_by_holder() {
+		new_test_ext().execute_with(|| {
+			for fp in ["vc_0", "vc_1", "vc_2"] {
+				create_held_credential(fp, holder_key(1));
+			}
+			create_held_credential("vc_3", holder_key(2));
+
+			let first_page = Verifiable::credentials_by_holder(&holder_key(1), None, 2);
+			assert_eq!(first_page.len(), 2);
+			let second_page =
+				Verifiable::credentials_by_holder(&holder_key(1), first_page.last().cloned(), 2);
+			assert_eq!(second_page.len(), 1);
+
+			let mut held: Vec<Vec<u8>> = first_page.into_iter().chain(second_page).collect();
+			held.sort();
+			let mut expected: Vec<Vec<u8>> =
+				["vc_0", "vc_1", "vc_2"].iter().map(|fp| fingerprint(fp).into_inner()).collect();
+			expected.sort();
+			assert_eq!(held, expected);
+			assert_eq!(
+				Verifiable::credentials_by_holder(&holder_key(2), None, 10),
+				vec![fingerprint("vc_3").into_inner()]
+			);
+			assert!(Verifiable::credentials_by_holder(&holder_key(3), None, 10).is_empty());
+		});
+	}
+
+	#[test]
+	fn update_moves_credential_to_new_holder() {
+		new_test_ext().execute_with(|| {
+			create_held_credential("vc_0", holder_key(1));
+			assert_ok!(Verifiable::update_verifiable_credential(
+				origin(ALICE),
+				fingerprint("vc_0"),
+				vc_metadata_input(holder_key(2))
+			));
+
+			assert!(Verifiable::credentials_by_holder(&holder_key(1), None, 10).is_empty());
+			assert_eq!(
+				Verifiable::credentials_by_holder(&holder_key(2), None, 10),
+				vec![fingerprint("vc_0").into_inner()]
+			);
+		});
+	}
+
+	#[test]
+	fn revoke_removes_holder_index() {
+		new_test_ext().execute_with(|| {
+			create_held_credential("vc_0", holder_key(1));
+			let holder = Verifiable::holder_key(&holder_key(1));
+			assert!(CredentialsByHolder::<Test>::contains_key(holder, fingerprint("vc_0")));
+
+			assert_ok!(Verifiable::revoke_verifiable_credential(
+				origin(ALICE),
+				fingerprint("vc_0")
+			));
+			assert!(!CredentialsByHolder::<Test>::contains_key(holder, fingerprint("vc_0")));
+		});
+	}
+
+	#[test]
+	fn migrate_indexes_new_fingerprint() {
+		new_test_ext().execute_with(|| {
+			create_held_credential("sha1", holder_key(1));
+			assert_ok!(Verifiable::trace_credential(
+				origin(ALICE),
+				Some(BOB),
+				fingerprint("sha1"),
+				VerifiableCredentialStatus::Created,
+				None,
+				None
+			));
+			assert_ok!(Verifiable::migrate_credential(
+				origin(ALICE),
+				fingerprint("sha1"),
+				fingerprint("sha256")
+			));
+
+			let mut held = Verifiable::credentials_by_holder(&holder_key(1), None, 10);
+			held.sort();
+			let mut expected =
+				vec![fingerprint("sha1").into_inner(), fingerprint("sha256").into_inner()];
+			expected.sort();
+			assert_eq!(held, expected);
+		});
+	}
+}
+
+mod credential_lineage {
+	use super::*;
+	use crate::{
+		CredentialChildren, CredentialParents, Error, VerifiableCredential,
+		VerifiableCredentialCurrentStatus, VerifiableCredentialStatus,
+	};
+	use frame_support::assert_noop;
+
+	fn fingerprint(fp: &str) -> BoundedVec<u8, VCFingerPrintSize> {
+		get_vc_fingerprint(fp)
+	}
+
+	fn fingerprints(fps: &[&str]) -> BoundedVec<BoundedVec<u8, VCFingerPrintSize>, MaxLineageSize> {
+		fps.iter().map(|fp| fingerprint(fp)).collect::<Vec<_>>().try_into().unwrap()
+	}
+
+	#[test]
+	fn split_and_merge() {
+		new_test_ext().execute_with(|| {
+			let pallet = create_credential("pallet");
+			assert_ok!(Verifiable::split_credential(
+				origin(ALICE),
+				pallet.clone(),
+				fingerprints(&["carton_0", "carton_1"])
+			));
+
+			assert_eq!(
+				VerifiableCredentialCurrentStatus::<Test>::get(&pallet),
+				Some(VerifiableCredentialStatus::Split)
+			);
+			let carton = VerifiableCredential::<Test>::get(fingerprint("carton_0")).unwrap();
+			assert_eq!(carton.account_id, Some(1));
+			assert_eq!(
+				VerifiableCredentialCurrentStatus::<Test>::get(fingerprint("carton_0")),
+				Some(VerifiableCredentialStatus::Created)
+			);
+
+			assert_ok!(Verifiable::merge_credentials(
+				origin(ALICE),
+				fingerprints(&["carton_0", "carton_1"]),
+				fingerprint("container")
+			));
+			assert_eq!(
+				VerifiableCredentialCurrentStatus::<Test>::get(fingerprint("carton_1")),
+				Some(VerifiableCredentialStatus::Merged)
+			);
+
+			let mut ancestors = Verifiable::credential_ancestors(&fingerprint("container"), 10);
+			ancestors.sort();
+			let mut expected: Vec<Vec<u8>> = ["carton_0", "carton_1", "pallet"]
+				.iter()
+				.map(|fp| fingerprint(fp).into_inner())
+				.collect();
+			expected.sort();
+			assert_eq!(ancestors, expected);
+
+			let descendants = Verifiable::credential_descendants(&fingerprint("pallet"), 10);
+			assert_eq!(descendants.len(), 3);
+			assert_eq!(descendants.last(), Some(&fingerprint("container").into_inner()));
+			assert_eq!(Verifiable::credential_descendants(&fingerprint("pallet"), 2).len(), 2);
+		});
+	}
+
+	#[test]
+	fn delete_removes_lineage() {
+		new_test_ext().execute_with(|| {
+			let pallet = create_credential("pallet");
+			assert_ok!(Verifiable::split_credential(
+				origin(ALICE),
+				pallet.clone(),
+				fingerprints(&["carton_0", "carton_1"])
+			));
+			assert_ok!(Verifiable::revoke_verifiable_credential(origin(ALICE), pallet.clone()));
+			assert_ok!(Verifiable::delete_verifiable_credential(origin(ALICE), pallet.clone(), 10));
+
+			assert_eq!(CredentialChildren::<Test>::iter_prefix(&pallet).count(), 0);
+			assert!(!CredentialParents::<Test>::contains_key(fingerprint("carton_0"), &pallet));
+			assert!(Verifiable::credential_ancestors(&fingerprint("carton_1"), 10).is_empty());
+		});
+	}
+
+	#[test]
+	fn split_terminated_credential() {
+		new_test_ext().execute_with(|| {
+			let pallet = create_credential("pallet");
+			assert_ok!(Verifiable::split_credential(
+				origin(ALICE),
+				pallet.clone(),
+				fingerprints(&["carton_0"])
+			));
+			assert_noop!(
+				Verifiable::split_credential(origin(ALICE), pallet, fingerprints(&["carton_1"])),
+				Error::<Test>::VerifiableCredentialTerminated
+			);
+		});
+	}
+
+	#[test]
+	fn split_by_non_issuer() {
+		new_test_ext().execute_with(|| {
+			let pallet = create_credential("pallet");
+			assert_noop!(
+				Verifiable::split_credential(origin(BOB), pallet, fingerprints(&["carton_0"])),
+				Error::<Test>::NotVerifiableCredentialIssuer
+			);
+		});
+	}
+
+	#[test]
+	fn invalid_lineage() {
+		new_test_ext().execute_with(|| {
+			let pallet = create_credential("pallet");
+			assert_noop!(
+				Verifiable::split_credential(origin(ALICE), pallet.clone(), fingerprints(&[])),
+				Error::<Test>::InvalidCredentialLineage
+			);
+			assert_noop!(
+				Verifiable::split_credential(
+					origin(ALICE),
+					pallet.clone(),
+					fingerprints(&["carton_0", "carton_0"])
+				),
+				Error::<Test>::InvalidCredentialLineage
+			);
+			assert_noop!(
+				Verifiable::merge_credentials(origin(ALICE), fingerprints(&["pallet"]), pallet),
+				Error::<Test>::InvalidCredentialLineage
+			);
+		});
+	}
+}
+
+mod migrate_credential {
+	use super::*;
+	use crate::{
+		CredentialMigratedFrom, CredentialMigratedTo, Error, VerifiableCredential,
+		VerifiableCredentialCurrentStatus, VerifiableCredentialStatus,
+	};
+	use frame_support::assert_noop;
+
+	fn fingerprint(fp: &str) -> BoundedVec<u8, VCFingerPrintSize> {
+		get_vc_fingerprint(fp)
+	}
+
+	#[test]
+	fn migrate_credential() {
+		new_test_ext().execute_with(|| {
+			let sha1 = create_credential("sha1");
+			assert_ok!(Verifiable::trace_credential(
+				origin(ALICE),
+				Some(BOB),
+				sha1.clone(),
+				VerifiableCredentialStatus::Created,
+				None,
+				None
+			));
+			assert_ok!(Verifiable::migrate_credential(
+				origin(ALICE),
+				sha1.clone(),
+				fingerprint("sha256")
+			));
+			assert_ok!(Verifiable::migrate_credential(
+				origin(ALICE),
+				fingerprint("sha256"),
+				fingerprint("blake2")
+			));
+
+			assert_eq!(CredentialMigratedTo::<Test>::get(&sha1), Some(fingerprint("sha256")));
+			assert_eq!(VerifiableCredential::<Test>::get(&sha1).unwrap().active, Some(false));
+			assert_eq!(
+				VerifiableCredentialCurrentStatus::<Test>::get(&sha1),
+				Some(VerifiableCredentialStatus::Migrated)
+			);
+			assert_eq!(
+				VerifiableCredential::<Test>::get(fingerprint("blake2")).unwrap().active,
+				Some(true)
+			);
+
+			let chain: Vec<Vec<u8>> = ["sha1", "sha256", "blake2"]
+				.iter()
+				.map(|fp| fingerprint(fp).into_inner())
+				.collect();
+			assert_eq!(Verifiable::credential_migration_chain(&chain[1], 10), chain);
+			assert_eq!(Verifiable::credential_migration_chain(&chain[2], 10), chain);
+			assert_eq!(Verifiable::credential_migration_chain(&chain[0], 2), chain[..2].to_vec());
+
+			let history: Vec<VerifiableCredentialStatus> = chain
+				.iter()
+				.flat_map(|fp| Verifiable::verifiable_credential_trail(fp, 0, u32::MAX))
+				.map(|vc_log| vc_log.status)
+				.collect();
+			assert_eq!(
+				history,
+				vec![
+					VerifiableCredentialStatus::Created,
+					VerifiableCredentialStatus::Migrated,
+					VerifiableCredentialStatus::Created,
+					VerifiableCredentialStatus::Migrated,
+					VerifiableCredentialStatus::Created,
+				]
+			);
+		});
+	}
+
+	#[test]
+	fn delete_removes_migration_links() {
+		new_test_ext().execute_with(|| {
+			let sha1 = create_credential("sha1");
+			assert_ok!(Verifiable::migrate_credential(
+				origin(ALICE),
+				sha1.clone(),
+				fingerprint("sha256")
+			));
+			assert_ok!(Verifiable::revoke_verifiable_credential(origin(ALICE), sha1.clone()));
+			assert_ok!(Verifiable::delete_verifiable_credential(origin(ALICE), sha1.clone(), 10));
+
+			assert_eq!(CredentialMigratedTo::<Test>::get(&sha1), None);
+			assert_eq!(CredentialMigratedFrom::<Test>::get(fingerprint("sha256")), None);
+			assert_eq!(
+				Verifiable::credential_migration_chain(&fingerprint("sha256"), 10),
+				vec![fingerprint("sha256").into_inner()]
+			);
+		});
+	}
+
+	#[test]
+	fn migrate_to_existing_fingerprint() {
+		new_test_ext().execute_with(|| {
+			let sha1 = create_credential("sha1");
+			let sha256 = create_credential("sha256");
+			assert_noop!(
+				Verifiable::migrate_credential(origin(ALICE), sha1, sha256),
+				Error::<Test>::VerifiableCredentialFingerPrintExists
+			);
+		});
+	}
+
+	#[test]
+	fn migrate_by_non_issuer() {
+		new_test_ext().execute_with(|| {
+			let sha1 = create_credential("sha1");
+			assert_noop!(
+				Verifiable::migrate_credential(origin(BOB), sha1, fingerprint("sha256")),
+				Error::<Test>::NotVerifiableCredentialIssuer
+			);
+		});
+	}
+}
+
+mod device_trace {
+	use super::*;
+	use crate::{
+		Call, DIDMetadataPayload, DeviceNonce, DeviceTracePayload, Devices, Error,
+		VerifiableCredentialStatus,
+	};
+	use codec::Encode;
+	use frame_support::{assert_noop, traits::Get, unsigned::ValidateUnsigned};
+	use sp_core::{ed25519, Pair, H256};
+	use sp_runtime::transaction_validity::{InvalidTransaction, TransactionSource};
+
+	fn device() -> ed25519::Pair {
+		ed25519::Pair::from_seed(&[7u8; 32])
+	}
+
+	fn create_did() -> BoundedVec<u8, DIDURISize> {
+		let did = DIDMetadataPayload {
+			signatures: BoundedVec::default(),
+			did_resolution_metadata: Some(get_did_resolution_metadata()),
+			did_document_metadata: Some(get_did_document_metadata()),
+			did_ref: None,
+			sender_account_id: ALICE,
+		};
+		assert_ok!(Verifiable::create_did(origin(ALICE), get_did_uri(), did));
+		get_did_uri()
+	}
+
+	fn register_device() -> BoundedVec<u8, VCFingerPrintSize> {
+		assert_ok!(Verifiable::register_device(
+			origin(ALICE),
+			create_did(),
+			device().public(),
+			"dock-scanner-1".as_bytes().to_vec().try_into().unwrap(),
+			vec![VerifiableCredentialStatus::Created, VerifiableCredentialStatus::Scanned]
+				.try_into()
+				.unwrap()
+		));
+
+		create_credential("vc_fingerprint")
+	}
+
+	fn signed_trace(
+		vc_fingerprint: &BoundedVec<u8, VCFingerPrintSize>,
+		status: VerifiableCredentialStatus,
+		nonce: u64,
+	) -> Call<Test> {
+		sign(DeviceTracePayload {
+			device_key: device().public(),
+			genesis_hash: System::block_hash(0),
+			nonce,
+			vc_fingerprint: vc_fingerprint.clone(),
+			status,
+			metadata: None,
+			evidence_hash: None,
+		})
+	}
+
+	fn sign(payload: DeviceTracePayload<H256, VCFingerPrintSize, TrailMetadataSize>) -> Call<Test> {
+		let signature = device().sign(&payload.encode());
+		Call::trace_credential_signed { payload, signature }
+	}
+
+	fn validate(call: &Call<Test>) -> Result<(), InvalidTransaction> {
+		Verifiable::validate_unsigned(TransactionSource::External, call)
+			.map(|_| ())
+			.map_err(|error| match error {
+				| sp_runtime::transaction_validity::TransactionValidityError::Invalid(error) =>
+					error,
+				| _ => InvalidTransaction::Call,
+			})
+	}
+
+	fn dispatch(call: Call<Test>) -> frame_support::dispatch::DispatchResultWithPostInfo {
+		match call {
+			| Call::trace_credential_signed { payload, signature } =>
+				Verifiable::trace_credential_signed(RuntimeOrigin::none(), payload, signature),
+			| _ => unreachable!(),
+		}
+	}
+
+	#[test]
+	fn register_device() {
+		new_test_ext().execute_with(|| {
+			let did_uri = create_did();
+			let label: BoundedVec<u8, DeviceLabelSize> =
+				"dock-scanner-1".as_bytes().to_vec().try_into().unwrap();
+			let statuses: BoundedVec<VerifiableCredentialStatus, MaxDeviceStatuses> =
+				vec![VerifiableCredentialStatus::Scanned].try_into().unwrap();
+			assert_noop!(
+				Verifiable::register_device(
+					origin(BOB),
+					did_uri.clone(),
+					device().public(),
+					label.clone(),
+					statuses.clone()
+				),
+				Error::<Test>::NotDIDController
+			);
+			assert_ok!(Verifiable::register_device(
+				origin(ALICE),
+				did_uri.clone(),
+				device().public(),
+				label.clone(),
+				statuses.clone()
+			));
+			assert_eq!(Devices::<Test>::get(device().public()).unwrap().label, label);
+			assert_noop!(
+				Verifiable::register_device(
+					origin(ALICE),
+					did_uri,
+					device().public(),
+					label,
+					statuses
+				),
+				Error::<Test>::DeviceExists
+			);
+		});
+	}
+
+	#[test]
+	fn trace_credential_signed() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = register_device();
+			let call = signed_trace(&vc_fingerprint, VerifiableCredentialStatus::Created, 0);
+			assert_ok!(validate(&call));
+			assert_ok!(dispatch(call.clone()));
+
+			let result = Verifiable::verifiable_credential_trail(&vc_fingerprint, 0, u32::MAX);
+			assert_eq!(result.len(), 1);
+			assert_eq!(result[0].account_id, Some(ALICE));
+			assert_eq!(result[0].status, VerifiableCredentialStatus::Created);
+			assert_eq!(DeviceNonce::<Test>::get(device().public()), 1);
+
+			assert_eq!(validate(&call), Err(InvalidTransaction::Stale));
+			assert_noop!(dispatch(call), Error::<Test>::InvalidDeviceNonce);
+		});
+	}
+
+	#[test]
+	fn invalid_device_trace() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = register_device();
+
+			let mut call = signed_trace(&vc_fingerprint, VerifiableCredentialStatus::Created, 0);
+			if let Call::trace_credential_signed { signature, .. } = &mut call {
+				*signature = ed25519::Pair::from_seed(&[8u8; 32]).sign(b"forged");
+			}
+			assert_eq!(validate(&call), Err(InvalidTransaction::BadProof));
+
+			let call = signed_trace(&vc_fingerprint, VerifiableCredentialStatus::Frozen, 0);
+			assert_eq!(validate(&call), Err(InvalidTransaction::Call));
+			assert_noop!(dispatch(call), Error::<Test>::DeviceStatusNotAllowed);
+
+			let mut payload =
+				match signed_trace(&vc_fingerprint, VerifiableCredentialStatus::Created, 0) {
+					| Call::trace_credential_signed { payload, .. } => payload,
+					| _ => unreachable!(),
+				};
+			payload.genesis_hash = H256::zero();
+			let call = sign(payload);
+			assert_eq!(validate(&call), Err(InvalidTransaction::BadProof));
+			assert_noop!(dispatch(call), Error::<Test>::InvalidDeviceGenesisHash);
+
+			assert_ok!(Verifiable::remove_device(origin(ALICE), device().public()));
+			let call = signed_trace(&vc_fingerprint, VerifiableCredentialStatus::Created, 0);
+			assert_eq!(validate(&call), Err(InvalidTransaction::BadSigner));
+		});
+	}
+
+	#[test]
+	fn failing_trace_is_invalid() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = register_device();
+
+			let call = signed_trace(&vc_fingerprint, VerifiableCredentialStatus::Scanned, 0);
+			assert_eq!(validate(&call), Err(InvalidTransaction::Call));
+			assert_noop!(dispatch(call), Error::<Test>::InvalidInitialStatus);
+
+			let call = signed_trace(
+				&get_vc_fingerprint("unknown"),
+				VerifiableCredentialStatus::Created,
+				0,
+			);
+			assert_eq!(validate(&call), Err(InvalidTransaction::Call));
+			assert_noop!(
+				dispatch(call),
+				Error::<Test>::VerifiableCredentialFingerPrintDoesNotExist
+			);
+			assert_eq!(DeviceNonce::<Test>::get(device().public()), 0);
+		});
+	}
+
+	#[test]
+	fn future_nonce_requires_previous() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = register_device();
+			let call = signed_trace(&vc_fingerprint, VerifiableCredentialStatus::Created, 1);
+			let transaction =
+				Verifiable::validate_unsigned(TransactionSource::External, &call).unwrap();
+			assert_eq!(
+				transaction.requires,
+				vec![("VerifiableDeviceTrace", (device().public(), 0u64)).encode()]
+			);
+			assert_noop!(dispatch(call), Error::<Test>::InvalidDeviceNonce);
+		});
+	}
+
+	#[test]
+	fn pre_dispatch_requires_device_nonce() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = register_device();
+			let future = signed_trace(&vc_fingerprint, VerifiableCredentialStatus::Created, 1);
+			assert_eq!(Verifiable::pre_dispatch(&future), Err(InvalidTransaction::Future.into()));
+
+			let call = signed_trace(&vc_fingerprint, VerifiableCredentialStatus::Created, 0);
+			assert_ok!(Verifiable::pre_dispatch(&call));
+			assert_ok!(dispatch(call.clone()));
+			assert_eq!(Verifiable::pre_dispatch(&call), Err(InvalidTransaction::Stale.into()));
+		});
+	}
+
+	#[test]
+	fn rate_limit() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let vc_fingerprint = register_device();
+			assert_ok!(dispatch(signed_trace(
+				&vc_fingerprint,
+				VerifiableCredentialStatus::Created,
+				0
+			)));
+			for nonce in 1..MaxDeviceTracesPerPeriod::get() as u64 {
+				assert_ok!(dispatch(signed_trace(
+					&vc_fingerprint,
+					VerifiableCredentialStatus::Scanned,
+					nonce
+				)));
+			}
+
+			let call = signed_trace(
+				&vc_fingerprint,
+				VerifiableCredentialStatus::Scanned,
+				MaxDeviceTracesPerPeriod::get() as u64,
+			);
+			assert_eq!(validate(&call), Err(InvalidTransaction::ExhaustsResources));
+			assert_noop!(dispatch(call.clone()), Error::<Test>::DeviceRateLimited);
+
+			System::set_block_number(1 + DeviceTracePeriod::get());
+			assert_ok!(validate(&call));
+			assert_ok!(dispatch(call));
+		});
+	}
+
+	#[test]
+	fn nonce_survives_device_removal() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = register_device();
+			let call = signed_trace(&vc_fingerprint, VerifiableCredentialStatus::Created, 0);
+			assert_ok!(dispatch(call.clone()));
+
+			assert_noop!(
+				Verifiable::remove_device(origin(BOB), device().public()),
+				Error::<Test>::NotDeviceController
+			);
+			assert_ok!(Verifiable::remove_device(origin(ALICE), device().public()));
+			assert_ok!(Verifiable::register_device(
+				origin(ALICE),
+				get_did_uri(),
+				device().public(),
+				BoundedVec::default(),
+				vec![VerifiableCredentialStatus::Created].try_into().unwrap()
+			));
+			assert_eq!(validate(&call), Err(InvalidTransaction::Stale));
+		});
+	}
+}
+
+mod timestamps {
+	use super::*;
+	use crate::{DIDMetadataPayload, VerifiableCredential};
+
+	#[test]
+	fn iso8601() {
+		assert_eq!(Verifiable::iso8601(0), b"1970-01-01T00:00:00Z".to_vec());
+		assert_eq!(Verifiable::iso8601(1_009_916_420_999), b"2002-01-01T20:20:20Z".to_vec());
+		assert_eq!(Verifiable::iso8601(951_868_800_000), b"2000-03-01T00:00:00Z".to_vec());
+		assert_eq!(Verifiable::iso8601(1_709_251_199_000), b"2024-02-29T23:59:59Z".to_vec());
+	}
+
+	#[test]
+	fn resolve_did() {
+		new_test_ext().execute_with(|| {
+			let did_uri = get_did_uri();
+			let did = DIDMetadataPayload {
+				signatures: BoundedVec::default(),
+				did_resolution_metadata: Some(get_did_resolution_metadata()),
+				did_document_metadata: Some(get_did_document_metadata()),
+				did_ref: None,
+				sender_account_id: ALICE,
+			};
+			Timestamp::set_timestamp(1_009_916_420_000);
+			assert_ok!(Verifiable::create_did(origin(ALICE), did_uri.clone(), did.clone()));
+			Timestamp::set_timestamp(1_709_251_199_000);
+			assert_ok!(Verifiable::update_did_document(origin(ALICE), did_uri.clone(), did));
+
+			let resolution = Verifiable::resolve_did(&did_uri).unwrap();
+			assert_eq!(resolution.did_uri, did_uri.to_vec());
+			assert_eq!(resolution.sender_account_id, ALICE);
+			assert_eq!(resolution.created, b"2002-01-01T20:20:20Z".to_vec());
+			assert_eq!(resolution.updated, b"2024-02-29T23:59:59Z".to_vec());
+			assert!(Verifiable::resolve_did(b"did:alto:unknown").is_none());
+		});
+	}
+
+	#[test]
+	fn credential_and_trail_timestamps() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = get_vc_fingerprint("vc_fingerprint");
+			let vc_metadata_input = get_vc_metadata_input(ALICE);
+			Timestamp::set_timestamp(1_000);
+			assert_ok!(Verifiable::create_verifiable_credential(
+				origin(ALICE),
+				vc_fingerprint.clone(),
+				vc_metadata_input.clone()
+			));
+			Timestamp::set_timestamp(2_000);
+			assert_ok!(Verifiable::update_verifiable_credential(
+				origin(ALICE),
+				vc_fingerprint.clone(),
+				vc_metadata_input
+			));
+			assert_ok!(Verifiable::trace_credential(
+				origin(ALICE),
+				Some(BOB),
+				vc_fingerprint.clone(),
+				crate::VerifiableCredentialStatus::Created,
+				None,
+				None
+			));
+
+			let metadata = VerifiableCredential::<Test>::get(&vc_fingerprint).unwrap();
+			assert_eq!(metadata.timestamp, 1_000);
+			assert_eq!(metadata.updated_timestamp, 2_000);
+			let trail = Verifiable::verifiable_credential_trail(&vc_fingerprint, 0, 1);
+			assert_eq!(trail[0].timestamp, Some(2_000));
+		});
+	}
+}
+
+mod archive_trail {
+	use super::*;
+	use crate::{
+		ArchivedTrailSegmentCount, ArchivedTrailSegments, Error, Event,
+		RevokedVerifiableCredential, VerifiableCredentialLog, VerifiableCredentialStatus,
+		VerifiableCredentialTrailHead, VerifiableCredentialTrailTail,
+	};
+	use frame_support::assert_noop;
+
+	fn create_traced_credential(traces: u32) -> BoundedVec<u8, VCFingerPrintSize> {
+		let vc_fingerprint = create_credential("vc_fingerprint");
+		for trace in 0..traces {
+			System::set_block_number(trace as u64 + 1);
+			let status = if trace == 0 {
+				VerifiableCredentialStatus::Created
+			} else {
+				VerifiableCredentialStatus::Scanned
+			};
+			assert_ok!(Verifiable::trace_credential(
+				origin(ALICE),
+				Some(BOB),
+				vc_fingerprint.clone(),
+				status,
+				None,
+				None
+			));
+		}
+		vc_fingerprint
+	}
+
+	#[test]
+	fn archive_trail() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_traced_credential(5);
+			let trail = Verifiable::verifiable_credential_trail(&vc_fingerprint, 0, u32::MAX);
+
+			// Entries logged at blocks 1 to 4 are older than `TrailArchiveAge`, at most
+			// `MaxArchiveEntries` of them are archived at once
+			System::set_block_number(14);
+			assert_ok!(Verifiable::archive_verifiable_credential_trail(
+				origin(BOB),
+				vc_fingerprint.clone()
+			));
+			let segment = ArchivedTrailSegments::<Test>::get(&vc_fingerprint, 0).unwrap();
+			assert_eq!((segment.start, segment.count), (0, 3));
+			assert_eq!(VerifiableCredentialTrailTail::<Test>::get(&vc_fingerprint), 3);
+			assert_eq!(VerifiableCredentialTrailHead::<Test>::get(&vc_fingerprint), 5);
+			assert_eq!(
+				Verifiable::verifiable_credential_trail(&vc_fingerprint, 0, u32::MAX),
+				trail[3..].to_vec()
+			);
+
+			// Entry logged at block 4 is old enough, the one at block 5 is not
+			assert_ok!(Verifiable::archive_verifiable_credential_trail(
+				origin(BOB),
+				vc_fingerprint.clone()
+			));
+			let segment = ArchivedTrailSegments::<Test>::get(&vc_fingerprint, 1).unwrap();
+			assert_eq!((segment.start, segment.count), (3, 1));
+			assert_eq!(ArchivedTrailSegmentCount::<Test>::get(&vc_fingerprint), 2);
+			assert_noop!(
+				Verifiable::archive_verifiable_credential_trail(
+					origin(BOB),
+					vc_fingerprint.clone()
+				),
+				Error::<Test>::NothingToArchive
+			);
+		});
+	}
+
+	#[test]
+	fn delete_archived_trail_in_pages() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_traced_credential(5);
+			System::set_block_number(14);
+			assert_ok!(Verifiable::archive_verifiable_credential_trail(
+				origin(BOB),
+				vc_fingerprint.clone()
+			));
+			assert_ok!(Verifiable::revoke_verifiable_credential(
+				origin(ALICE),
+				vc_fingerprint.clone()
+			));
+
+			// Archived entries are not purged again, only the segment holding them
+			assert_ok!(Verifiable::delete_verifiable_credential(
+				origin(ALICE),
+				vc_fingerprint.clone(),
+				1
+			));
+			System::assert_last_event(
+				Event::VerifiableCredentialTrailPurged {
+					vc_fingerprint: vc_fingerprint.clone(),
+					account_id: ALICE,
+					purged_trail_entries: 1,
+					remaining_trail_entries: 1,
+					block_number: 14,
+				}
+				.into(),
+			);
+			assert_eq!(VerifiableCredentialTrailTail::<Test>::get(&vc_fingerprint), 4);
+			assert!(RevokedVerifiableCredential::<Test>::contains_key(&vc_fingerprint));
+
+			assert_ok!(Verifiable::delete_verifiable_credential(
+				origin(ALICE),
+				vc_fingerprint.clone(),
+				2
+			));
+			System::assert_last_event(
+				Event::VerifiableCredentialDeleted {
+					vc_fingerprint: vc_fingerprint.clone(),
+					account_id: ALICE,
+					purged_trail_entries: 1,
+					deleted_block_number: 14,
+				}
+				.into(),
+			);
+			assert!(ArchivedTrailSegments::<Test>::get(&vc_fingerprint, 0).is_none());
+			assert_eq!(ArchivedTrailSegmentCount::<Test>::get(&vc_fingerprint), 0);
+			assert_eq!(VerifiableCredentialTrailHead::<Test>::get(&vc_fingerprint), 0);
+			assert_eq!(VerifiableCredentialTrailTail::<Test>::get(&vc_fingerprint), 0);
+			assert!(!RevokedVerifiableCredential::<Test>::contains_key(&vc_fingerprint));
+		});
+	}
+
+	#[test]
+	fn verify_archived_trail_log() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_traced_credential(3);
+			let trail = Verifiable::verifiable_credential_trail(&vc_fingerprint, 0, u32::MAX);
+			System::set_block_number(20);
+			assert_ok!(Verifiable::archive_verifiable_credential_trail(
+				origin(BOB),
+				vc_fingerprint.clone()
+			));
+
+			let leaves: Vec<_> = trail
+				.iter()
+				.enumerate()
+				.map(|(sequence, vc_log)| Verifiable::trail_leaf(sequence as u32, vc_log))
+				.collect();
+			for (sequence, vc_log) in trail.iter().enumerate() {
+				let proof = Verifiable::build_merkle_proof(leaves.clone(), sequence);
+				assert!(Verifiable::verify_archived_trail_log(
+					&vc_fingerprint,
+					0,
+					sequence as u32,
+					vc_log,
+					&proof
+				));
+			}
+
+			let proof = Verifiable::build_merkle_proof(leaves, 1);
+			let tampered =
+				VerifiableCredentialLog { account_id: Some(CHARLIE), ..trail[1].clone() };
+			assert!(!Verifiable::verify_archived_trail_log(
+				&vc_fingerprint,
+				0,
+				1,
+				&tampered,
+				&proof
+			));
+			assert!(!Verifiable::verify_archived_trail_log(
+				&vc_fingerprint,
+				0,
+				2,
+				&trail[1],
+				&proof
+			));
+			assert!(!Verifiable::verify_archived_trail_log(
+				&vc_fingerprint,
+				1,
+				1,
+				&trail[1],
+				&proof
+			));
+		});
+	}
+}
+
+mod query_trail {
+	use super::*;
+	use crate::{TrailFilter, VerifiableCredentialStatus};
+
+	fn filter() -> TrailFilter<u64, u64> {
+		TrailFilter { status: None, account_id: None, from_block: None, to_block: None }
+	}
+
+	fn create_traced_credential() -> BoundedVec<u8, VCFingerPrintSize> {
+		let vc_fingerprint = create_credential("vc_fingerprint");
+		let traces = [
+			(BOB, VerifiableCredentialStatus::Created),
+			(CHARLIE, VerifiableCredentialStatus::Scanned),
+			(BOB, VerifiableCredentialStatus::Scanned),
+			(CHARLIE, VerifiableCredentialStatus::Suspended),
+			(BOB, VerifiableCredentialStatus::Resumed),
+			(CHARLIE, VerifiableCredentialStatus::Scanned),
+		];
+		for (block_number, (account_id, status)) in traces.into_iter().enumerate() {
+			System::set_block_number(block_number as u64 + 1);
+			assert_ok!(Verifiable::trace_credential(
+				origin(ALICE),
+				Some(account_id),
+				vc_fingerprint.clone(),
+				status,
+				None,
+				None
+			));
+		}
+		vc_fingerprint
+	}
+
+	fn sequences(page: &crate::TrailPage<u64, u64, sp_core::H256, TrailMetadataSize>) -> Vec<u32> {
+		page.entries.iter().map(|(sequence, _)| *sequence).collect()
+	}
+
+	#[test]
+	fn filter_trail() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_traced_credential();
+
+			let scanned =
+				TrailFilter { status: Some(VerifiableCredentialStatus::Scanned), ..filter() };
+			let page = Verifiable::query_trail(&vc_fingerprint, scanned, None, 10).unwrap();
+			assert_eq!(sequences(&page), vec![1, 2, 5]);
+
+			let by_bob = TrailFilter { account_id: Some(BOB), ..filter() };
+			let page = Verifiable::query_trail(&vc_fingerprint, by_bob, None, 10).unwrap();
+			assert_eq!(sequences(&page), vec![0, 2, 4]);
+
+			let block_range = TrailFilter { from_block: Some(2), to_block: Some(4), ..filter() };
+			let page = Verifiable::query_trail(&vc_fingerprint, block_range, None, 10).unwrap();
+			assert_eq!(sequences(&page), vec![1, 2, 3]);
+
+			let scanned_by_charlie = TrailFilter {
+				status: Some(VerifiableCredentialStatus::Scanned),
+				account_id: Some(CHARLIE),
+				..filter()
+			};
+			let page =
+				Verifiable::query_trail(&vc_fingerprint, scanned_by_charlie, None, 10).unwrap();
+			assert_eq!(sequences(&page), vec![1, 5]);
+		});
+	}
+
+	#[test]
+	fn paged_query() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_traced_credential();
+
+			let page = Verifiable::query_trail(&vc_fingerprint, filter(), None, 4).unwrap();
+			assert_eq!(sequences(&page), vec![0, 1, 2, 3]);
+			assert_eq!(page.next_cursor, Some(4));
+
+			let page =
+				Verifiable::query_trail(&vc_fingerprint, filter(), page.next_cursor, 4).unwrap();
+			assert_eq!(sequences(&page), vec![4, 5]);
+			assert_eq!(page.next_cursor, None);
+		});
+	}
+
+	#[test]
+	fn status_summary() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_traced_credential();
+			let page = Verifiable::query_trail(&vc_fingerprint, filter(), None, 0).unwrap();
+			assert!(page.entries.is_empty());
+			assert_eq!(page.summary.current_status, Some(VerifiableCredentialStatus::Scanned));
+			assert_eq!(page.summary.active, Some(true));
+			assert_eq!(page.summary.trail_length, 6);
+			assert_eq!(page.summary.archived_entries, 0);
+			assert_eq!(page.summary.last_block_number, Some(6));
+
+			assert!(Verifiable::query_trail(b"unknown", filter(), None, 10).is_none());
+		});
+	}
+}
+
+mod custom_status {
+	use super::*;
+	use crate::{CustomStatusCount, Error, VerifiableCredentialStatus};
+	use frame_support::assert_noop;
+
+	fn label(label: &str) -> BoundedVec<u8, CustomStatusLabelSize> {
+		label.as_bytes().to_vec().try_into().unwrap()
+	}
+
+	fn trace(
+		vc_fingerprint: &BoundedVec<u8, VCFingerPrintSize>,
+		status: VerifiableCredentialStatus,
+	) -> frame_support::dispatch::DispatchResultWithPostInfo {
+		Verifiable::trace_credential(
+			origin(CHARLIE),
+			Some(CHARLIE),
+			vc_fingerprint.clone(),
+			status,
+			None,
+			None,
+		)
+	}
+
+	#[test]
+	fn register_custom_status() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Verifiable::register_custom_status(origin(ALICE), 2, label("graded")));
+			assert_ok!(Verifiable::register_custom_status(origin(ALICE), 1, label("enrolled")));
+			assert_eq!(
+				Verifiable::custom_status_vocabulary(&ALICE),
+				vec![(1, b"enrolled".to_vec()), (2, b"graded".to_vec())]
+			);
+			assert_noop!(
+				Verifiable::register_custom_status(origin(ALICE), 1, label("admitted")),
+				Error::<Test>::CustomStatusExists
+			);
+			assert_noop!(
+				Verifiable::register_custom_status(origin(ALICE), 3, label("graduated")),
+				Error::<Test>::TooManyCustomStatuses
+			);
+
+			assert_ok!(Verifiable::remove_custom_status(origin(ALICE), 2));
+			assert_eq!(CustomStatusCount::<Test>::get(ALICE), 1);
+			assert_ok!(Verifiable::register_custom_status(origin(ALICE), 3, label("graduated")));
+			assert_noop!(
+				Verifiable::remove_custom_status(origin(BOB), 3),
+				Error::<Test>::CustomStatusNotRegistered
+			);
+		});
+	}
+
+	#[test]
+	fn trace_custom_status() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Verifiable::register_custom_status(origin(ALICE), 1, label("enrolled")));
+			assert_ok!(Verifiable::register_custom_status(origin(BOB), 2, label("graded")));
+			let vc_fingerprint =
+				create_credential_with(ALICE, "transcript", get_vc_metadata_input(BOB));
+
+			assert_noop!(
+				trace(&vc_fingerprint, VerifiableCredentialStatus::Custom(1)),
+				Error::<Test>::InvalidInitialStatus
+			);
+			assert_ok!(trace(&vc_fingerprint, VerifiableCredentialStatus::Created));
+			assert_ok!(trace(&vc_fingerprint, VerifiableCredentialStatus::Custom(1)));
+			assert_ok!(trace(&vc_fingerprint, VerifiableCredentialStatus::Scanned));
+
+			// Statuses registered by another issuer or removed ones are rejected
+			assert_noop!(
+				trace(&vc_fingerprint, VerifiableCredentialStatus::Custom(2)),
+				Error::<Test>::CustomStatusNotRegistered
+			);
+			assert_ok!(Verifiable::remove_custom_status(origin(ALICE), 1));
+			assert_noop!(
+				trace(&vc_fingerprint, VerifiableCredentialStatus::Custom(1)),
+				Error::<Test>::CustomStatusNotRegistered
+			);
+
+			let trail = Verifiable::verifiable_credential_trail(&vc_fingerprint, 0, u32::MAX);
+			assert_eq!(trail[1].status, VerifiableCredentialStatus::Custom(1));
+		});
+	}
+}
+
+mod roles {
+	use super::*;
+	use crate::{
+		DIDMetadataPayload, DeviceTracePayload, Error, RoleMember, Roles,
+		VerifiableCredentialStatus,
+	};
+	use codec::Encode;
+	use frame_support::assert_noop;
+	use sp_core::{ed25519, Pair};
+
+	fn name(name: &str) -> BoundedVec<u8, RoleNameSize> {
+		name.as_bytes().to_vec().try_into().unwrap()
+	}
+
+	fn statuses(
+		statuses: Vec<VerifiableCredentialStatus>,
+	) -> BoundedVec<VerifiableCredentialStatus, MaxRoleStatuses> {
+		statuses.try_into().unwrap()
+	}
+
+	fn create_did(account_id: u64) -> BoundedVec<u8, DIDURISize> {
+		let did = DIDMetadataPayload {
+			signatures: BoundedVec::default(),
+			did_resolution_metadata: Some(get_did_resolution_metadata()),
+			did_document_metadata: Some(get_did_document_metadata()),
+			did_ref: None,
+			sender_account_id: account_id,
+		};
+		assert_ok!(Verifiable::create_did(origin(account_id), get_did_uri(), did));
+		get_did_uri()
+	}
+
+	fn trace(
+		tracer: u64,
+		vc_fingerprint: &BoundedVec<u8, VCFingerPrintSize>,
+		status: VerifiableCredentialStatus,
+	) -> frame_support::dispatch::DispatchResultWithPostInfo {
+		Verifiable::trace_credential(
+			origin(tracer),
+			Some(tracer),
+			vc_fingerprint.clone(),
+			status,
+			None,
+			None,
+		)
+	}
+
+	#[test]
+	fn role_registry() {
+		new_test_ext().execute_with(|| {
+			let allowed = statuses(vec![VerifiableCredentialStatus::Created]);
+			assert_ok!(Verifiable::register_role(
+				origin(ALICE),
+				1,
+				name("manufacturer"),
+				allowed.clone()
+			));
+			assert_noop!(
+				Verifiable::register_role(origin(ALICE), 1, name("carrier"), allowed.clone()),
+				Error::<Test>::RoleExists
+			);
+			assert_ok!(Verifiable::register_role(
+				origin(ALICE),
+				2,
+				name("carrier"),
+				allowed.clone()
+			));
+			assert_noop!(
+				Verifiable::register_role(origin(ALICE), 3, name("customs"), allowed),
+				Error::<Test>::TooManyRoles
+			);
+
+			let bob = RoleMember::Account(BOB);
+			assert_noop!(
+				Verifiable::assign_role(origin(ALICE), bob.clone(), 3),
+				Error::<Test>::RoleDoesNotExist
+			);
+			assert_noop!(
+				Verifiable::assign_role(origin(ALICE), RoleMember::DID(get_did_uri()), 1),
+				Error::<Test>::DIDDoesNotExist
+			);
+			assert_ok!(Verifiable::assign_role(origin(ALICE), bob.clone(), 1));
+			assert_noop!(Verifiable::remove_role(origin(ALICE), 1), Error::<Test>::RoleHasMembers);
+
+			// Reassigning moves the member to the new role
+			assert_ok!(Verifiable::assign_role(origin(ALICE), bob.clone(), 2));
+			assert_eq!(Roles::<Test>::get(ALICE, 1).unwrap().members, 0);
+			assert_eq!(Roles::<Test>::get(ALICE, 2).unwrap().members, 1);
+			assert_ok!(Verifiable::remove_role(origin(ALICE), 1));
+
+			assert_ok!(Verifiable::unassign_role(origin(ALICE), bob.clone()));
+			assert_noop!(
+				Verifiable::unassign_role(origin(ALICE), bob),
+				Error::<Test>::RoleNotAssigned
+			);
+			assert_ok!(Verifiable::remove_role(origin(ALICE), 2));
+			assert_noop!(
+				Verifiable::remove_role(origin(ALICE), 2),
+				Error::<Test>::RoleDoesNotExist
+			);
+		});
+	}
+
+	#[test]
+	fn trace_within_role() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_credential("shipment");
+
+			// Issuers without roles accept any tracer
+			assert_ok!(trace(CHARLIE, &vc_fingerprint, VerifiableCredentialStatus::Created));
+
+			assert_ok!(Verifiable::register_role(
+				origin(ALICE),
+				1,
+				name("carrier"),
+				statuses(vec![VerifiableCredentialStatus::Scanned])
+			));
+			assert_ok!(Verifiable::register_role(
+				origin(ALICE),
+				2,
+				name("customs"),
+				statuses(vec![
+					VerifiableCredentialStatus::Suspended,
+					VerifiableCredentialStatus::Resumed
+				])
+			));
+			assert_ok!(Verifiable::assign_role(origin(ALICE), RoleMember::Account(BOB), 1));
+			assert_ok!(Verifiable::assign_role(origin(ALICE), RoleMember::Account(CHARLIE), 2));
+
+			assert_noop!(
+				trace(ALICE, &vc_fingerprint, VerifiableCredentialStatus::Scanned),
+				Error::<Test>::RoleNotAssigned
+			);
+			assert_noop!(
+				trace(CHARLIE, &vc_fingerprint, VerifiableCredentialStatus::Scanned),
+				Error::<Test>::RoleStatusNotAllowed
+			);
+			assert_ok!(trace(BOB, &vc_fingerprint, VerifiableCredentialStatus::Scanned));
+			assert_ok!(trace(CHARLIE, &vc_fingerprint, VerifiableCredentialStatus::Suspended));
+
+			let trail = Verifiable::verifiable_credential_trail(&vc_fingerprint, 0, u32::MAX);
+			assert_eq!(
+				trail.iter().map(|log| log.role).collect::<Vec<_>>(),
+				vec![None, Some(1), Some(2)]
+			);
+		});
+	}
+
+	#[test]
+	fn trace_within_device_did_role() {
+		new_test_ext().execute_with(|| {
+			let device = ed25519::Pair::from_seed(&[7u8; 32]);
+			let did_uri = create_did(BOB);
+			assert_ok!(Verifiable::register_device(
+				origin(BOB),
+				did_uri.clone(),
+				device.public(),
+				"gate-scanner".as_bytes().to_vec().try_into().unwrap(),
+				vec![VerifiableCredentialStatus::Created, VerifiableCredentialStatus::Scanned]
+					.try_into()
+					.unwrap()
+			));
+			let vc_fingerprint = create_credential("shipment");
+
+			assert_ok!(Verifiable::register_role(
+				origin(ALICE),
+				1,
+				name("manufacturer"),
+				statuses(vec![VerifiableCredentialStatus::Created])
+			));
+			assert_ok!(Verifiable::register_role(
+				origin(ALICE),
+				2,
+				name("customs"),
+				statuses(vec![VerifiableCredentialStatus::Scanned])
+			));
+			// The device DID role takes precedence over the role of its controller
+			assert_ok!(Verifiable::assign_role(origin(ALICE), RoleMember::Account(BOB), 1));
+			assert_ok!(Verifiable::assign_role(origin(ALICE), RoleMember::DID(did_uri), 2));
+			assert_ok!(trace(BOB, &vc_fingerprint, VerifiableCredentialStatus::Created));
+
+			let payload = |status, nonce| DeviceTracePayload {
+				device_key: device.public(),
+				nonce,
+				vc_fingerprint: vc_fingerprint.clone(),
+				status,
+				metadata: None,
+				evidence_hash: None,
+			};
+			let created = payload(VerifiableCredentialStatus::Created, 0);
+			assert_noop!(
+				Verifiable::trace_credential_signed(
+					RuntimeOrigin::none(),
+					created.clone(),
+					device.sign(&created.encode())
+				),
+				Error::<Test>::RoleStatusNotAllowed
+			);
+			let scanned = payload(VerifiableCredentialStatus::Scanned, 0);
+			assert_ok!(Verifiable::trace_credential_signed(
+				RuntimeOrigin::none(),
+				scanned.clone(),
+				device.sign(&scanned.encode())
+			));
+
+			let trail = Verifiable::verifiable_credential_trail(&vc_fingerprint, 0, u32::MAX);
+			assert_eq!(trail[0].role, Some(1));
+			assert_eq!(trail[1].role, Some(2));
+			assert_eq!(trail[1].account_id, Some(BOB));
+		});
+	}
+}
+
+mod deposits {
+	use super::*;
+	use crate::{
+		CredentialDeposits, DIDDeposits, DIDMetadataPayload, Error, StorageDeposit, TrailDeposits,
+		VerifiableCredentialStatus,
+	};
+	use codec::Encode;
+	use frame_support::assert_noop;
+
+	fn did_input(sender_account_id: u64) -> DIDMetadataPayload<u64, MetadataSize> {
+		DIDMetadataPayload {
+			signatures: BoundedVec::default(),
+			did_resolution_metadata: Some(get_did_resolution_metadata()),
+			did_document_metadata: Some(get_did_document_metadata()),
+			did_ref: None,
+			sender_account_id,
+		}
+	}
+
+	#[test]
+	fn did_deposit() {
+		new_test_ext().execute_with(|| {
+			let did_uri = get_did_uri();
+			assert_ok!(Verifiable::create_did(origin(ALICE), did_uri.clone(), did_input(ALICE)));
+			let did = Verifiable::get_did_document(&did_uri).unwrap();
+			let deposit = Verifiable::deposit_for(did_uri.len() + did.encoded_size());
+			assert_eq!(deposit, DepositBase::get() + (did_uri.len() + did.encoded_size()) as u64);
+			assert_eq!(Balances::reserved_balance(ALICE), deposit);
+			assert_eq!(
+				DIDDeposits::<Test>::get(&did_uri),
+				Some(StorageDeposit { depositor: ALICE, amount: deposit })
+			);
+
+			// Only the controller may update the DID and take over its deposit
+			let mut update = did_input(BOB);
+			update.did_ref = Some("ipfs://did".as_bytes().to_vec().try_into().unwrap());
+			assert_noop!(
+				Verifiable::update_did_document(origin(BOB), did_uri.clone(), update.clone()),
+				Error::<Test>::NotDIDController
+			);
+			assert_noop!(
+				Verifiable::revoke_did_document(origin(BOB), did_uri.clone()),
+				Error::<Test>::NotDIDController
+			);
+
+			// Handing the DID over to BOB lets BOB update it, moving the deposit to BOB
+			assert_ok!(Verifiable::update_did_document(
+				origin(ALICE),
+				did_uri.clone(),
+				did_input(BOB)
+			));
+			assert_ok!(Verifiable::update_did_document(origin(BOB), did_uri.clone(), update));
+			let did = Verifiable::get_did_document(&did_uri).unwrap();
+			let updated_deposit = Verifiable::deposit_for(did_uri.len() + did.encoded_size());
+			assert!(updated_deposit > deposit);
+			assert_eq!(Balances::reserved_balance(ALICE), 0);
+			assert_eq!(Balances::reserved_balance(BOB), updated_deposit);
+
+			assert_ok!(Verifiable::revoke_did_document(origin(BOB), did_uri.clone()));
+			assert_eq!(Balances::reserved_balance(BOB), 0);
+			assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE);
+			assert!(DIDDeposits::<Test>::get(&did_uri).is_none());
+		});
+	}
+
+	#[test]
+	fn credential_and_trail_deposits() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint =
+				create_credential_with(ALICE, "vc_fingerprint", get_vc_metadata_input(BOB));
+			let credential_deposit = CredentialDeposits::<Test>::get(&vc_fingerprint).unwrap();
+			assert_eq!(credential_deposit.depositor, ALICE);
+			assert_eq!(Balances::reserved_balance(ALICE), credential_deposit.amount);
+
+			assert_ok!(Verifiable::trace_credential(
+				origin(CHARLIE),
+				Some(CHARLIE),
+				vc_fingerprint.clone(),
+				VerifiableCredentialStatus::Created,
+				None,
+				None
+			));
+			let trail_deposit = TrailDeposits::<Test>::get(&vc_fingerprint, 0).unwrap();
+			let trail = Verifiable::verifiable_credential_trail(&vc_fingerprint, 0, u32::MAX);
+			assert_eq!(trail_deposit.depositor, CHARLIE);
+			assert_eq!(trail_deposit.amount, Verifiable::deposit_for(trail[0].encoded_size()));
+			assert_eq!(Balances::reserved_balance(CHARLIE), trail_deposit.amount);
+
+			// Revoking refunds the credential deposit, deleting refunds the trail deposits
+			assert_ok!(Verifiable::revoke_verifiable_credential(
+				origin(BOB),
+				vc_fingerprint.clone()
+			));
+			assert_eq!(Balances::reserved_balance(ALICE), 0);
+			assert_eq!(Balances::reserved_balance(CHARLIE), trail_deposit.amount);
+
+			assert_ok!(Verifiable::delete_verifiable_credential(
+				origin(ALICE),
+				vc_fingerprint.clone(),
+				10
+			));
+			assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+			assert!(TrailDeposits::<Test>::iter_prefix(&vc_fingerprint).next().is_none());
+		});
+	}
+
+	#[test]
+	fn insufficient_balance() {
+		new_test_ext().execute_with(|| {
+			let account_id = 4;
+			assert_noop!(
+				Verifiable::create_did(origin(account_id), get_did_uri(), did_input(account_id)),
+				pallet_balances::Error::<Test>::InsufficientBalance
+			);
+		});
+	}
+}
+
+mod feeless_trace {
+	use super::*;
+	use crate::{RoleMember, TrailDeposits, VerifiableCredentialStatus};
+	use frame_support::assert_noop;
+
+	fn create_role_credential() -> BoundedVec<u8, VCFingerPrintSize> {
+		let vc_fingerprint = create_credential("shipment");
+		assert_ok!(Verifiable::register_role(
+			origin(ALICE),
+			1,
+			"scanner".as_bytes().to_vec().try_into().unwrap(),
+			vec![VerifiableCredentialStatus::Created, VerifiableCredentialStatus::Scanned]
+				.try_into()
+				.unwrap()
+		));
+		assert_ok!(Verifiable::assign_role(origin(ALICE), RoleMember::Account(BOB), 1));
+		vc_fingerprint
+	}
+
+	#[test]
+	fn role_holders_trace_feeless() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_role_credential();
+
+			assert!(Verifiable::is_feeless_trace(
+				&BOB,
+				&vc_fingerprint,
+				&VerifiableCredentialStatus::Scanned
+			));
+			assert!(!Verifiable::is_feeless_trace(
+				&BOB,
+				&vc_fingerprint,
+				&VerifiableCredentialStatus::Suspended
+			));
+			assert!(!Verifiable::is_feeless_trace(
+				&CHARLIE,
+				&vc_fingerprint,
+				&VerifiableCredentialStatus::Scanned
+			));
+			assert!(!Verifiable::is_feeless_trace(
+				&BOB,
+				&"unknown".as_bytes().to_vec().try_into().unwrap(),
+				&VerifiableCredentialStatus::Scanned
+			));
+		});
+	}
+
+	#[test]
+	fn quota_resets_every_era() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_role_credential();
+			let status = VerifiableCredentialStatus::Scanned;
+
+			for left in (1..=MaxFeelessTracesPerEra::get()).rev() {
+				assert_eq!(Verifiable::feeless_traces_left(&BOB), left);
+				Verifiable::note_feeless_trace(&BOB);
+			}
+			assert_eq!(Verifiable::feeless_traces_left(&BOB), 0);
+			assert_eq!(Verifiable::feeless_trace_slot(&BOB), (0, MaxFeelessTracesPerEra::get()));
+			assert!(!Verifiable::is_feeless_trace(&BOB, &vc_fingerprint, &status));
+
+			System::set_block_number(FeelessTraceEra::get() - 1);
+			assert!(!Verifiable::is_feeless_trace(&BOB, &vc_fingerprint, &status));
+			System::set_block_number(FeelessTraceEra::get());
+			assert_eq!(Verifiable::feeless_traces_left(&BOB), MaxFeelessTracesPerEra::get());
+			assert_eq!(Verifiable::feeless_trace_slot(&BOB), (1, 0));
+			assert!(Verifiable::is_feeless_trace(&BOB, &vc_fingerprint, &status));
+		});
+	}
+
+	#[test]
+	fn role_holders_fund_their_deposits() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_role_credential();
+			let reserved = Balances::reserved_balance(ALICE);
+
+			assert_ok!(Verifiable::trace_credential(
+				origin(BOB),
+				Some(BOB),
+				vc_fingerprint.clone(),
+				VerifiableCredentialStatus::Created,
+				None,
+				None
+			));
+			let trail_deposit = TrailDeposits::<Test>::get(&vc_fingerprint, 0).unwrap();
+			assert_eq!(trail_deposit.depositor, BOB);
+			assert_eq!(Balances::reserved_balance(ALICE), reserved);
+			assert_eq!(Balances::reserved_balance(BOB), trail_deposit.amount);
+		});
+	}
+
+	#[test]
+	fn issuer_funds_feeless_deposits() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = create_role_credential();
+			let scanner = 4;
+			assert_ok!(Verifiable::assign_role(origin(ALICE), RoleMember::Account(scanner), 1));
+			assert_eq!(Balances::free_balance(scanner), 0);
+			let reserved = Balances::reserved_balance(ALICE);
+
+			assert!(Verifiable::is_feeless_trace(
+				&scanner,
+				&vc_fingerprint,
+				&VerifiableCredentialStatus::Created
+			));
+			Verifiable::note_feeless_trace(&scanner);
+			assert_ok!(Verifiable::trace_credential(
+				origin(scanner),
+				None,
+				vc_fingerprint.clone(),
+				VerifiableCredentialStatus::Created,
+				None,
+				None
+			));
+			Verifiable::end_feeless_trace();
+
+			let trail_deposit = TrailDeposits::<Test>::get(&vc_fingerprint, 0).unwrap();
+			assert_eq!(trail_deposit.depositor, ALICE);
+			assert_eq!(Balances::reserved_balance(ALICE), reserved + trail_deposit.amount);
+
+			assert_noop!(
+				Verifiable::trace_credential(
+					origin(scanner),
+					None,
+					vc_fingerprint,
+					VerifiableCredentialStatus::Scanned,
+					None,
+					None
+				),
+				pallet_balances::Error::<Test>::InsufficientBalance
+			);
+		});
+	}
+}
+
+mod rate_limit {
+	use super::*;
+	use crate::{
+		AccountOperations, Error, IssuerTraces, RateLimitExempt, VerifiableCredentialStatus,
+	};
+	use frame_support::{assert_noop, traits::Get};
+	use sp_runtime::DispatchError;
+
+	fn trace(
+		tracer: u64,
+		vc_fingerprint: &BoundedVec<u8, VCFingerPrintSize>,
+		status: VerifiableCredentialStatus,
+	) -> frame_support::dispatch::DispatchResultWithPostInfo {
+		Verifiable::trace_credential(
+			origin(tracer),
+			Some(tracer),
+			vc_fingerprint.clone(),
+			status,
+			None,
+			None,
+		)
+	}
+
+	#[test]
+	fn account_rate_limit() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let vc_fingerprint = create_credential("vc_fingerprint");
+			assert_ok!(trace(BOB, &vc_fingerprint, VerifiableCredentialStatus::Created));
+			for _ in 1..MaxOperationsPerWindow::get() {
+				assert_ok!(trace(BOB, &vc_fingerprint, VerifiableCredentialStatus::Scanned));
+			}
+			assert_eq!(AccountOperations::<Test>::get(BOB), (0, MaxOperationsPerWindow::get()));
+			assert_noop!(
+				trace(BOB, &vc_fingerprint, VerifiableCredentialStatus::Scanned),
+				Error::<Test>::AccountRateLimited
+			);
+			// Other accounts are counted separately
+			assert_ok!(trace(CHARLIE, &vc_fingerprint, VerifiableCredentialStatus::Scanned));
+
+			// The counter is reset lazily in the next window
+			System::set_block_number(RateLimitWindow::get());
+			assert_ok!(trace(BOB, &vc_fingerprint, VerifiableCredentialStatus::Scanned));
+			assert_eq!(AccountOperations::<Test>::get(BOB), (1, 1));
+		});
+	}
+
+	#[test]
+	fn issuer_rate_limit() {
+		new_test_ext().execute_with(|| {
+			let max_traces: u32 = MaxIssuerTracesPerWindow::get();
+			let vc_fingerprint = create_credential("vc_fingerprint");
+			// Lift the account rate limit of the third party so it reaches the issuer window
+			assert_ok!(Verifiable::set_rate_limit_exemption(RuntimeOrigin::root(), CHARLIE, true));
+			assert_ok!(trace(CHARLIE, &vc_fingerprint, VerifiableCredentialStatus::Created));
+			for _ in 1..max_traces {
+				assert_ok!(trace(CHARLIE, &vc_fingerprint, VerifiableCredentialStatus::Scanned));
+			}
+			assert_eq!(IssuerTraces::<Test>::get(ALICE, CHARLIE), (0, max_traces));
+			assert_noop!(
+				trace(CHARLIE, &vc_fingerprint, VerifiableCredentialStatus::Scanned),
+				Error::<Test>::IssuerRateLimited
+			);
+
+			// Other tracers of the issuer are counted separately
+			assert_ok!(trace(BOB, &vc_fingerprint, VerifiableCredentialStatus::Scanned));
+			assert_eq!(IssuerTraces::<Test>::get(ALICE, BOB), (0, 1));
+
+			assert_ok!(Verifiable::set_rate_limit_exemption(RuntimeOrigin::root(), ALICE, true));
+			assert_ok!(trace(CHARLIE, &vc_fingerprint, VerifiableCredentialStatus::Scanned));
+		});
+	}
+
+	#[test]
+	fn exemption() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Verifiable::set_rate_limit_exemption(origin(ALICE), ALICE, true),
+				DispatchError::BadOrigin
+			);
+			assert_ok!(Verifiable::set_rate_limit_exemption(RuntimeOrigin::root(), BOB, true));
+			assert!(RateLimitExempt::<Test>::contains_key(BOB));
+
+			let vc_fingerprint = create_credential("vc_fingerprint");
+			assert_ok!(trace(BOB, &vc_fingerprint, VerifiableCredentialStatus::Created));
+			for _ in 0..MaxOperationsPerWindow::get() {
+				assert_ok!(trace(BOB, &vc_fingerprint, VerifiableCredentialStatus::Scanned));
+			}
+			assert_eq!(AccountOperations::<Test>::get(BOB), (0, 0));
+
+			assert_ok!(Verifiable::set_rate_limit_exemption(RuntimeOrigin::root(), BOB, false));
+			assert!(!RateLimitExempt::<Test>::contains_key(BOB));
+		});
+	}
+}
+
+mod migrations {
+	use super::*;
+	use crate::{
+		migrations::v2::{is_trail_pending, migrate_trails, v1, MigrateToV2, TrailMigrationCursor},
+		DIDDocument, Error, Pallet, VerifiableCredential, VerifiableCredentialCurrentStatus,
+		VerifiableCredentialStatus, VerifiableCredentialTrail, VerifiableCredentialTrailHead,
+	};
+	use frame_support::{
+		assert_noop,
+		storage::unhashed,
+		traits::{ConstU64, GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+	};
+
+	type MigrateToV2Test = MigrateToV2<Test, ConstU64<CHARLIE>>;
+
+	fn legacy_fingerprint(fingerprint: &str) -> BoundedVec<u8, VCFingerPrintSize> {
+		fingerprint.as_bytes().to_vec().try_into().unwrap()
+	}
+
+	fn seed_v1_storage() {
+		StorageVersion::new(1).put::<Pallet<Test>>();
+		unhashed::put(
+			&DIDDocument::<Test>::hashed_key_for(get_did_uri()),
+			&v1::DID::<u64, u64, MetadataSize> {
+				signatures: get_did_document_metadata(),
+				did_resolution_metadata: Some(get_did_resolution_metadata()),
+				did_document_metadata: Some(get_did_document_metadata()),
+				block_number: 1,
+				updated_block_number: 2,
+				did_ref: None,
+				sender_account_id: ALICE,
+			},
+		);
+		for (fingerprint, account_id) in
+			[("vc_fingerprint_0", Some(BOB)), ("vc_fingerprint_1", None)]
+		{
+			unhashed::put(
+				&VerifiableCredential::<Test>::hashed_key_for(legacy_fingerprint(fingerprint)),
+				&v1::VerifiableCredentialMetadata::<u64, u64, PublicKeySize> {
+					account_id,
+					public_key: get_public_key(b"public_key".to_vec()),
+					block_number: 3,
+					updated_block_number: 3,
+					active: Some(true),
+				},
+			);
+		}
+		let trail = vec![
+			v1::VerifiableCredentialLog {
+				account_id: Some(ALICE),
+				status: VerifiableCredentialStatus::Created,
+				block_number: Some(3),
+			},
+			v1::VerifiableCredentialLog {
+				account_id: Some(CHARLIE),
+				status: VerifiableCredentialStatus::Scanned,
+				block_number: Some(4),
+			},
+		];
+		v1::VerifiableCredentialTrail::<Test>::insert(
+			legacy_fingerprint("vc_fingerprint_0"),
+			BoundedVec::<_, MetadataSize>::try_from(trail).unwrap(),
+		);
+		v1::VerifiableCredentialTrail::<Test>::insert(
+			legacy_fingerprint("vc_fingerprint_1"),
+			BoundedVec::<_, MetadataSize>::try_from(vec![v1::VerifiableCredentialLog {
+				account_id: Some(ALICE),
+				status: VerifiableCredentialStatus::Created,
+				block_number: Some(3),
+			}])
+			.unwrap(),
+		);
+	}
+
+	#[test]
+	fn migrates_v1_storage() {
+		new_test_ext().execute_with(|| {
+			seed_v1_storage();
+			MigrateToV2Test::on_runtime_upgrade();
+			assert_eq!(Pallet::<Test>::on_chain_storage_version(), 2);
+
+			let did = DIDDocument::<Test>::get(get_did_uri()).unwrap();
+			assert_eq!(did.signatures, get_did_document_metadata());
+			assert_eq!(did.did_resolution_metadata, Some(get_did_resolution_metadata()));
+			assert_eq!((did.block_number, did.updated_block_number), (1, 2));
+			assert_eq!((did.timestamp, did.updated_timestamp), (0, 0));
+			assert_eq!(did.sender_account_id, ALICE);
+			assert_eq!(
+				Verifiable::dids_by_account(&ALICE, None, 10),
+				vec![get_did_uri().into_inner()]
+			);
+
+			// Credentials keep their controller and are issued by the configured issuer
+			let traced = legacy_fingerprint("vc_fingerprint_0");
+			let vc = VerifiableCredential::<Test>::get(&traced).unwrap();
+			assert_eq!(vc.account_id, Some(BOB));
+			assert_eq!(vc.issuer, CHARLIE);
+			assert_eq!(
+				Verifiable::credentials_by_holder(
+					&get_public_key(b"public_key".to_vec()),
+					None,
+					10
+				)
+				.len(),
+				2
+			);
+			assert_eq!(vc.active, Some(true));
+			assert_eq!((vc.timestamp, vc.expiry_block_number, vc.issuer_did), (0, None, None));
+
+			let uncontrolled = legacy_fingerprint("vc_fingerprint_1");
+			let vc = VerifiableCredential::<Test>::get(&uncontrolled).unwrap();
+			assert_eq!((vc.account_id, vc.issuer), (None, CHARLIE));
+
+			// Trails are migrated in the following blocks and cannot be appended to until then
+			assert!(is_trail_pending::<Test>(&traced));
+			assert_eq!(VerifiableCredentialTrailHead::<Test>::get(&traced), 0);
+			assert_noop!(
+				Verifiable::trace_credential(
+					origin(BOB),
+					Some(BOB),
+					traced.clone(),
+					VerifiableCredentialStatus::Scanned,
+					None,
+					None
+				),
+				Error::<Test>::TrailMigrationPending
+			);
+			Verifiable::on_initialize(1);
+			assert!(!TrailMigrationCursor::<Test>::exists());
+			assert!(!is_trail_pending::<Test>(&traced));
+			assert_eq!(VerifiableCredentialTrailHead::<Test>::get(&uncontrolled), 1);
+
+			assert_eq!(VerifiableCredentialTrailHead::<Test>::get(&traced), 2);
+			assert_eq!(
+				VerifiableCredentialCurrentStatus::<Test>::get(&traced),
+				Some(VerifiableCredentialStatus::Scanned)
+			);
+			let log = VerifiableCredentialTrail::<Test>::get(&traced, 1).unwrap();
+			assert_eq!(log.account_id, Some(CHARLIE));
+			assert_eq!(log.status, VerifiableCredentialStatus::Scanned);
+			assert_eq!(log.block_number, Some(4));
+			assert_eq!((log.timestamp, log.metadata, log.role), (None, None, None));
+			assert_eq!(VerifiableCredentialTrail::<Test>::iter_prefix(&traced).count(), 2);
+			assert_ok!(Verifiable::trace_credential(
+				origin(BOB),
+				Some(BOB),
+				traced,
+				VerifiableCredentialStatus::Scanned,
+				None,
+				None
+			));
+		});
+	}
+
+	#[test]
+	fn migrates_trails_in_steps() {
+		new_test_ext().execute_with(|| {
+			seed_v1_storage();
+			MigrateToV2Test::on_runtime_upgrade();
+			let fingerprints =
+				[legacy_fingerprint("vc_fingerprint_0"), legacy_fingerprint("vc_fingerprint_1")];
+
+			migrate_trails::<Test>(1);
+			assert!(TrailMigrationCursor::<Test>::exists());
+			assert_eq!(fingerprints.iter().filter(|vc| is_trail_pending::<Test>(vc)).count(), 1);
+
+			migrate_trails::<Test>(1);
+			assert!(fingerprints.iter().all(|vc| !is_trail_pending::<Test>(vc)));
+			assert_eq!(VerifiableCredentialTrailHead::<Test>::get(&fingerprints[0]), 2);
+			assert_eq!(VerifiableCredentialTrailHead::<Test>::get(&fingerprints[1]), 1);
+
+			migrate_trails::<Test>(1);
+			assert!(!TrailMigrationCursor::<Test>::exists());
+		});
+	}
+
+	#[test]
+	fn skips_migrated_storage() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(2).put::<Pallet<Test>>();
+			v1::VerifiableCredentialTrail::<Test>::insert(
+				legacy_fingerprint("vc_fingerprint_0"),
+				BoundedVec::<_, MetadataSize>::default(),
+			);
+			MigrateToV2Test::on_runtime_upgrade();
+			assert!(v1::VerifiableCredentialTrail::<Test>::contains_key(legacy_fingerprint(
+				"vc_fingerprint_0"
+			)));
+		});
+	}
+}
+
+mod genesis_config {
+	use super::*;
+	use crate::{
+		CredentialDeposits, CredentialsByIssuerDID, CustomStatusVocabulary, DIDDeposits,
+		DIDDocument, GenesisConfig, GenesisCredential, GenesisCustomStatus, GenesisDID,
+		GenesisRole, RoleAssignments, RoleCount, RoleMember, Roles, VerifiableCredential,
+		VerifiableCredentialStatus,
+	};
+	use frame_support::traits::GenesisBuild;
+
+	const ALICE_DID: &str = "did:alto:alice";
+	const BOB_DID: &str = "did:alto:bob";
+
+	fn genesis_ext(config: GenesisConfig<Test>) -> sp_io::TestExternalities {
+		let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+		pallet_balances::GenesisConfig::<Test> {
+			balances: vec![(ALICE, INITIAL_BALANCE), (BOB, INITIAL_BALANCE)],
+		}
+		.assimilate_storage(&mut storage)
+		.unwrap();
+		config.assimilate_storage(&mut storage).unwrap();
+		storage.into()
+	}
+
+	fn genesis_config() -> GenesisConfig<Test> {
+		GenesisConfig {
+			dids: vec![
+				GenesisDID {
+					did_uri: ALICE_DID.into(),
+					controller: ALICE,
+					did_document_metadata: get_did_document_metadata().into_inner(),
+				},
+				GenesisDID {
+					did_uri: BOB_DID.into(),
+					controller: BOB,
+					did_document_metadata: get_did_document_metadata().into_inner(),
+				},
+			],
+			credentials: vec![GenesisCredential {
+				vc_fingerprint: get_vc_fingerprint("vc_fingerprint").into_inner(),
+				issuer: ALICE,
+				public_key: vec![0, 1, 2, 3, 4, 5, 6, 7],
+				issuer_did: Some(ALICE_DID.into()),
+				subject_did: Some(BOB_DID.into()),
+			}],
+			custom_statuses: vec![GenesisCustomStatus {
+				issuer: ALICE,
+				status_id: 7,
+				label: "customs cleared".into(),
+			}],
+			roles: vec![GenesisRole {
+				issuer: ALICE,
+				role_id: 1,
+				name: "carrier".into(),
+				allowed_statuses: vec![
+					VerifiableCredentialStatus::Created,
+					VerifiableCredentialStatus::Scanned,
+					VerifiableCredentialStatus::Custom(7),
+				],
+				members: vec![BOB, CHARLIE],
+			}],
+		}
+	}
+
+	#[test]
+	fn seeds_storage() {
+		genesis_ext(genesis_config()).execute_with(|| {
+			let alice_did: BoundedVec<u8, DIDURISize> =
+				ALICE_DID.as_bytes().to_vec().try_into().unwrap();
+			let did = DIDDocument::<Test>::get(&alice_did).unwrap();
+			assert_eq!(did.sender_account_id, ALICE);
+			assert_eq!(did.did_document_metadata, Some(get_did_document_metadata()));
+
+			let vc_fingerprint = get_vc_fingerprint("vc_fingerprint");
+			let vc = VerifiableCredential::<Test>::get(&vc_fingerprint).unwrap();
+			assert_eq!(vc.issuer, ALICE);
+			assert_eq!(vc.issuer_did, Some(alice_did.clone()));
+			assert_eq!(vc.active, Some(true));
+			assert!(CredentialsByIssuerDID::<Test>::contains_key(&alice_did, &vc_fingerprint));
+
+			// Deposits are reserved like for records created by extrinsics
+			let did_deposit = DIDDeposits::<Test>::get(&alice_did).unwrap();
+			let credential_deposit = CredentialDeposits::<Test>::get(&vc_fingerprint).unwrap();
+			assert_eq!((did_deposit.depositor, credential_deposit.depositor), (ALICE, ALICE));
+			assert_eq!(
+				Balances::reserved_balance(ALICE),
+				did_deposit.amount + credential_deposit.amount
+			);
+
+			assert_eq!(
+				CustomStatusVocabulary::<Test>::get(ALICE, 7).unwrap().into_inner(),
+				b"customs cleared".to_vec()
+			);
+			assert_eq!(RoleCount::<Test>::get(ALICE), 1);
+			assert_eq!(Roles::<Test>::get(ALICE, 1).unwrap().members, 2);
+			assert_eq!(RoleAssignments::<Test>::get(ALICE, RoleMember::Account(CHARLIE)), Some(1));
+
+			// Genesis records can be used like any other
+			assert_ok!(Verifiable::trace_credential(
+				origin(BOB),
+				None,
+				vc_fingerprint.clone(),
+				VerifiableCredentialStatus::Created,
+				None,
+				None
+			));
+			assert_ok!(Verifiable::trace_credential(
+				origin(CHARLIE),
+				None,
+				vc_fingerprint,
+				VerifiableCredentialStatus::Custom(7),
+				None,
+				None
+			));
+		});
+	}
+
+	#[test]
+	fn chain_spec_json() {
+		let json = serde_json::to_value(genesis_config()).unwrap();
+		assert_eq!(json["dids"][0]["didUri"], ALICE_DID);
+		assert_eq!(json["customStatuses"][0]["label"], "customs cleared");
+		assert_eq!(json["credentials"][0]["publicKey"], "0x0001020304050607");
+		assert_eq!(json["credentials"][0]["subjectDid"], BOB_DID);
+
+		let config: GenesisConfig<Test> = serde_json::from_value(json).unwrap();
+		assert_eq!(config.credentials, genesis_config().credentials);
+	}
+
+	#[test]
+	#[should_panic(expected = "verifiable credential DID is not a genesis DID")]
+	fn rejects_unknown_credential_dids() {
+		let mut config = genesis_config();
+		config.dids.pop();
+		genesis_ext(config);
+	}
+}
+
+mod fingerprints {
+	use super::*;
+	use crate::{Blake2b256, Error, FingerprintHasher, Keccak256, Sha2256, VerifiableCredential};
+	use frame_support::assert_noop;
+
+	fn content(content: &str) -> BoundedVec<u8, MaxCredentialContentSize> {
+		content.as_bytes().to_vec().try_into().unwrap()
+	}
+
+	#[test]
+	fn multihash_prefixes() {
+		assert_eq!(Blake2b256::prefix(), vec![0xa0, 0xe4, 0x02, 0x20]);
+		assert_eq!(Sha2256::prefix(), vec![0x12, 0x20]);
+		assert_eq!(Keccak256::prefix(), vec![0x1b, 0x20]);
+
+		let fingerprint = Sha2256::fingerprint(b"diploma");
+		assert_eq!(fingerprint.len(), 34);
+		assert!(Sha2256::is_fingerprint(&fingerprint));
+		assert!(!Blake2b256::is_fingerprint(&fingerprint));
+		assert!(!Sha2256::is_fingerprint(&fingerprint[..33]));
+	}
+
+	#[test]
+	fn create_malformed_fingerprint() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint: BoundedVec<u8, VCFingerPrintSize> =
+				"vc_fingerprint".as_bytes().to_vec().try_into().unwrap();
+			assert_noop!(
+				Verifiable::create_verifiable_credential(
+					origin(ALICE),
+					vc_fingerprint,
+					get_vc_metadata_input(BOB)
+				),
+				Error::<Test>::MalformedFingerprint
+			);
+		});
+	}
+
+	#[test]
+	fn anchor_credential_content() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint = get_vc_fingerprint("diploma");
+			assert_ok!(Verifiable::anchor_credential_content(
+				origin(ALICE),
+				vc_fingerprint.clone(),
+				content("diploma"),
+				get_vc_metadata_input(BOB)
+			));
+			let vc = VerifiableCredential::<Test>::get(&vc_fingerprint).unwrap();
+			assert_eq!(vc.issuer, ALICE);
+			assert_eq!(vc.account_id, Some(BOB));
+		});
+	}
+
+	#[test]
+	fn anchor_mismatched_content() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Verifiable::anchor_credential_content(
+					origin(ALICE),
+					get_vc_fingerprint("diploma"),
+					content("forged diploma"),
+					get_vc_metadata_input(BOB)
+				),
+				Error::<Test>::FingerprintMismatch
+			);
+			let vc_fingerprint: BoundedVec<u8, VCFingerPrintSize> =
+				"diploma".as_bytes().to_vec().try_into().unwrap();
+			assert_noop!(
+				Verifiable::anchor_credential_content(
+					origin(ALICE),
+					vc_fingerprint,
+					content("diploma"),
+					get_vc_metadata_input(BOB)
+				),
+				Error::<Test>::MalformedFingerprint
+			);
+		});
+	}
+}
+
+mod dids_by_account {
+	use super::*;
+	use crate::{DIDMetadataPayload, DIDsByAccount};
+
+	fn did_uri(did_uri: &str) -> BoundedVec<u8, DIDURISize> {
+		did_uri.as_bytes().to_vec().try_into().unwrap()
+	}
+
+	fn did_input(sender_account_id: u64) -> DIDMetadataPayload<u64, MetadataSize> {
+		DIDMetadataPayload {
+			signatures: BoundedVec::default(),
+			did_resolution_metadata: None,
+			did_document_metadata: Some(get_did_document_metadata()),
+			did_ref: None,
+			sender_account_id,
+		}
+	}
+
+	fn create_did(did_uri: &BoundedVec<u8, DIDURISize>, signer: u64, sender_account_id: u64) {
+		assert_ok!(Verifiable::create_did(
+			origin(signer),
+			did_uri.clone(),
+			did_input(sender_account_id)
+		));
+	}
+
+	#[test]
+	fn page_dids_by_account() {
+		new_test_ext().execute_with(|| {
+			let mut dids: Vec<Vec<u8>> = Vec::new();
+			for uri in ["did:alto:0", "did:alto:1", "did:alto:2"] {
+				create_did(&did_uri(uri), BOB, BOB);
+				dids.push(uri.as_bytes().to_vec());
+			}
+			create_did(&did_uri("did:alto:charlie"), CHARLIE, CHARLIE);
+
+			let first_page = Verifiable::dids_by_account(&BOB, None, 2);
+			assert_eq!(first_page.len(), 2);
+			let second_page = Verifiable::dids_by_account(&BOB, first_page.last().cloned(), 2);
+			assert_eq!(second_page.len(), 1);
+
+			let mut listed: Vec<Vec<u8>> = first_page.into_iter().chain(second_page).collect();
+			listed.sort();
+			assert_eq!(listed, dids);
+			assert_eq!(
+				Verifiable::dids_by_account(&CHARLIE, None, 10),
+				vec![b"did:alto:charlie".to_vec()]
+			);
+			assert!(Verifiable::dids_by_account(&ALICE, None, 10).is_empty());
+		});
+	}
+
+	#[test]
+	fn listed_under_signer() {
+		new_test_ext().execute_with(|| {
+			let did_uri = get_did_uri();
+			create_did(&did_uri, ALICE, BOB);
+
+			assert!(DIDsByAccount::<Test>::contains_key(ALICE, &did_uri));
+			assert!(Verifiable::dids_by_account(&BOB, None, 10).is_empty());
+		});
+	}
+
+	#[test]
+	fn update_did_sender() {
+		new_test_ext().execute_with(|| {
+			let did_uri = get_did_uri();
+			create_did(&did_uri, BOB, BOB);
+
+			assert_ok!(Verifiable::update_did_document(
+				origin(BOB),
+				did_uri.clone(),
+				did_input(CHARLIE)
+			));
+			assert!(DIDsByAccount::<Test>::contains_key(BOB, &did_uri));
+			assert!(!DIDsByAccount::<Test>::contains_key(CHARLIE, &did_uri));
+
+			assert_ok!(Verifiable::update_did_document(
+				origin(CHARLIE),
+				did_uri.clone(),
+				did_input(CHARLIE)
+			));
+			assert!(!DIDsByAccount::<Test>::contains_key(BOB, &did_uri));
+			assert!(DIDsByAccount::<Test>::contains_key(CHARLIE, &did_uri));
+		});
+	}
+
+	#[test]
+	fn revoke_did() {
+		new_test_ext().execute_with(|| {
+			let did_uri = get_did_uri();
+			create_did(&did_uri, BOB, BOB);
+			assert_ok!(Verifiable::update_did_document(
+				origin(BOB),
+				did_uri.clone(),
+				did_input(CHARLIE)
+			));
+
+			assert_ok!(Verifiable::revoke_did_document(origin(CHARLIE), did_uri));
+			assert!(Verifiable::dids_by_account(&BOB, None, 10).is_empty());
+			assert!(Verifiable::dids_by_account(&CHARLIE, None, 10).is_empty());
+		});
+	}
+}
+
+mod credential_status {
+	use super::*;
+	use crate::{
+		CredentialValidity, VerifiableCredentialMetadataPayload, VerifiableCredentialStatus,
+	};
+
+	fn create_verifiable_credential(
+		expiry_block_number: Option<u64>,
+	) -> BoundedVec<u8, VCFingerPrintSize> {
+		let vc_fingerprint = create_credential_with(
+			ALICE,
+			"vc_fingerprint",
+			VerifiableCredentialMetadataPayload {
+				expiry_block_number,
+				..get_vc_metadata_input(BOB)
+			},
+		);
+		assert_ok!(Verifiable::trace_credential(
+			origin(ALICE),
+			Some(BOB),
+			vc_fingerprint.clone(),
+			VerifiableCredentialStatus::Created,
+			None,
+			None
+		));
+		vc_fingerprint
+	}
+
+	#[test]
+	fn resolve_verifiable_credential() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let vc_fingerprint = create_verifiable_credential(Some(5));
+
+			let vc = Verifiable::resolve_verifiable_credential(&vc_fingerprint).unwrap();
+			assert_eq!(vc.vc_fingerprint, vc_fingerprint.to_vec());
+			assert_eq!(vc.account_id, Some(BOB));
+			assert_eq!(vc.issuer, ALICE);
+			assert_eq!(vc.public_key, get_vc_metadata_input(BOB).public_key.into_inner());
+			assert_eq!((vc.block_number, vc.expiry_block_number), (1, Some(5)));
+			assert_eq!(vc.created, b"1970-01-01T00:00:00Z".to_vec());
+			assert!(
+				Verifiable::resolve_verifiable_credential(&get_vc_fingerprint("unknown")).is_none()
+			);
+		});
+	}
+
+	#[test]
+	fn valid_and_expired_credential() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let vc_fingerprint = create_verifiable_credential(Some(5));
+
+			let status = Verifiable::credential_status(&vc_fingerprint).unwrap();
+			assert_eq!(status.validity, CredentialValidity::Valid);
+			assert_eq!(status.current_status, Some(VerifiableCredentialStatus::Created));
+			assert_eq!(status.issuer, Some(ALICE));
+			assert_eq!(status.expiry_block_number, Some(5));
+
+			System::set_block_number(5);
+			let status = Verifiable::credential_status(&vc_fingerprint).unwrap();
+			assert_eq!(status.validity, CredentialValidity::Expired);
+			assert!(Verifiable::credential_status(&get_vc_fingerprint("unknown")).is_none());
+		});
+	}
+
+	#[test]
+	fn revoked_and_deleted_credential() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let vc_fingerprint = create_verifiable_credential(None);
+
+			System::set_block_number(2);
+			assert_ok!(Verifiable::revoke_verifiable_credential(
+				origin(BOB),
+				vc_fingerprint.clone()
+			));
+			let status = Verifiable::credential_status(&vc_fingerprint).unwrap();
+			assert_eq!(status.validity, CredentialValidity::Revoked);
+			assert_eq!((status.issuer, status.revoked_block_number), (Some(ALICE), Some(2)));
+
+			System::set_block_number(3);
+			assert_ok!(Verifiable::delete_verifiable_credential(
+				origin(ALICE),
+				vc_fingerprint.clone(),
+				10
+			));
+			let status = Verifiable::credential_status(&vc_fingerprint).unwrap();
+			assert_eq!(status.validity, CredentialValidity::Deleted);
+			assert_eq!((status.issuer, status.deleted_block_number), (None, Some(3)));
+		});
+	}
+}
+
+mod storage_keys {
+	use super::*;
+	use crate::DIDDocument;
+
+	#[test]
+	fn did_storage_keys() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(
+				Verifiable::did_storage_keys(&get_did_uri()),
+				vec![DIDDocument::<Test>::hashed_key_for(get_did_uri())]
+			);
+			assert!(Verifiable::did_storage_keys(&[0u8; 1024]).is_empty());
+		});
+	}
+
+	#[test]
+	fn credential_storage_keys_watch_revocation() {
+		new_test_ext().execute_with(|| {
+			let vc_fingerprint =
+				create_credential_with(ALICE, "vc_fingerprint", get_vc_metadata_input(BOB));
+
+			let keys = Verifiable::credential_storage_keys(&vc_fingerprint);
+			let read = || keys.iter().map(|key| sp_io::storage::get(key)).collect::<Vec<_>>();
+			let before = read();
+			assert_ok!(Verifiable::revoke_verifiable_credential(origin(BOB), vc_fingerprint));
+			let after = read();
+			assert_ne!(before[0], after[0]);
+			assert_ne!(before[1], after[1]);
+			assert!(Verifiable::credential_storage_keys(&[0u8; 1024]).is_empty());
+		});
+	}
 }
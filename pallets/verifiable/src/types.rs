@@ -1,11 +1,20 @@
 use frame_support::{traits::Get, BoundedVec, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound};
 use scale_info::TypeInfo;
-use sp_std::fmt::Debug;
+use sp_std::{fmt::Debug, prelude::*};
 
 use frame_support::codec::{Decode, Encode, MaxEncodedLen};
 
 use sp_core::ed25519::Signature;
+
+/// Public key of a registered scanning device
+pub type DevicePublicKey = sp_core::ed25519::Public;
+
+/// Signature of a registered scanning device
+pub type DeviceSignature = Signature;
 use sp_runtime::RuntimeDebug;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 // TODO: Implement this
 /// Stores Signatures by DID Controllers
 #[derive(
@@ -51,6 +60,13 @@ where
 
 	// Updated timestamp
 	pub updated_block_number: BlockNumber,
+
+	// Created time in milliseconds since the Unix epoch
+	pub timestamp: u64,
+
+	// Updated time in milliseconds since the Unix epoch
+	pub updated_timestamp: u64,
+
 	// IPFS  URI of the DID document
 	pub did_ref: Option<BoundedVec<u8, MetadataSize>>,
 
@@ -58,6 +74,33 @@ where
 	pub sender_account_id: AccountId,
 }
 
+/// DID Document resolution output
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct DIDResolution<AccountId, BlockNumber> {
+	pub did_uri: Vec<u8>,
+
+	pub did_resolution_metadata: Option<Vec<u8>>,
+
+	// DID Document Metadata
+	pub did_document_metadata: Option<Vec<u8>>,
+
+	// IPFS  URI of the DID document
+	pub did_ref: Option<Vec<u8>>,
+
+	// Sender AccountId
+	pub sender_account_id: AccountId,
+
+	pub block_number: BlockNumber,
+
+	pub updated_block_number: BlockNumber,
+
+	// ISO 8601 created time
+	pub created: Vec<u8>,
+
+	// ISO 8601 updated time
+	pub updated: Vec<u8>,
+}
+
 /// DID Metadata Input Payload
 #[derive(
 	Encode,
@@ -102,12 +145,14 @@ where
 	MaxEncodedLen,
 	Default,
 )]
-#[scale_info(skip_type_params(PublicKeySize))]
-#[codec(mel_bound(AccountId: MaxEncodedLen))]
-pub struct VerifiableCredentialMetadataPayload<AccountId, PublicKeySize>
+#[scale_info(skip_type_params(PublicKeySize, DIDURISize))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+pub struct VerifiableCredentialMetadataPayload<AccountId, BlockNumber, PublicKeySize, DIDURISize>
 where
 	AccountId: Clone + PartialEq + Debug,
+	BlockNumber: Clone + PartialEq + Debug + PartialOrd,
 	PublicKeySize: Get<u32>,
+	DIDURISize: Get<u32>,
 {
 	// Controller's AccountId
 	pub account_id: Option<AccountId>,
@@ -117,8 +162,16 @@ where
 
 	// active
 	pub active: Option<bool>,
-}
 
+	// Block number from which the credential is expired
+	pub expiry_block_number: Option<BlockNumber>,
+
+	// Issuer's DID URI
+	pub issuer_did: Option<BoundedVec<u8, DIDURISize>>,
+
+	// Subject's DID URI
+	pub subject_did: Option<BoundedVec<u8, DIDURISize>>,
+}
 
 /// Verifiable Credential Metadata
 #[derive(
@@ -132,13 +185,14 @@ where
 	MaxEncodedLen,
 	Default,
 )]
-#[scale_info(skip_type_params(PublicKeySize))]
+#[scale_info(skip_type_params(PublicKeySize, DIDURISize))]
 #[codec(mel_bound(AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
-pub struct VerifiableCredentialMetadata<AccountId, BlockNumber, PublicKeySize>
+pub struct VerifiableCredentialMetadata<AccountId, BlockNumber, PublicKeySize, DIDURISize>
 where
 	AccountId: Clone + PartialEq + Debug,
 	BlockNumber: Clone + PartialEq + Debug + PartialOrd,
 	PublicKeySize: Get<u32>,
+	DIDURISize: Get<u32>,
 {
 	// Controller's AccountId
 	pub account_id: Option<AccountId>,
@@ -152,8 +206,46 @@ where
 	// Block number
 	pub updated_block_number: BlockNumber,
 
+	// Created time in milliseconds since the Unix epoch
+	pub timestamp: u64,
+
+	// Updated time in milliseconds since the Unix epoch
+	pub updated_timestamp: u64,
+
 	// active
 	pub active: Option<bool>,
+
+	// Issuer's AccountId
+	pub issuer: AccountId,
+
+	// Block number from which the credential is expired
+	pub expiry_block_number: Option<BlockNumber>,
+
+	// Issuer's DID URI
+	pub issuer_did: Option<BoundedVec<u8, DIDURISize>>,
+
+	// Subject's DID URI
+	pub subject_did: Option<BoundedVec<u8, DIDURISize>>,
+}
+
+/// Revocation record kept for a verifiable credential until it is deleted
+#[derive(
+	Encode, Decode, CloneNoBound, PartialEqNoBound, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen,
+)]
+#[codec(mel_bound(AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+pub struct VerifiableCredentialRevocation<AccountId, BlockNumber>
+where
+	AccountId: Clone + PartialEq + Debug,
+	BlockNumber: Clone + PartialEq + Debug + PartialOrd,
+{
+	// Issuer's AccountId
+	pub issuer: AccountId,
+
+	// Revoker's AccountId
+	pub revoked_by: AccountId,
+
+	// Revoked block number
+	pub revoked_block_number: BlockNumber,
 }
 
 impl<MetadataSize> DIDSignature<MetadataSize>
@@ -171,6 +263,7 @@ where
 
 /// Verifiable Credential Status
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum VerifiableCredentialStatus {
 	Pending,
 	Created,
@@ -181,30 +274,610 @@ pub enum VerifiableCredentialStatus {
 	Split,
 	Merged,
 	Frozen,
+	// Status id registered in the custom status vocabulary of the issuer
+	Custom(u16),
+}
+
+impl VerifiableCredentialStatus {
+	/// No status may follow a terminal status
+	pub fn is_terminal(&self) -> bool {
+		matches!(self, Self::Frozen | Self::Migrated | Self::Split | Self::Merged)
+	}
+
+	/// Whether the status records a lineage change, which only `split_credential`,
+	/// `merge_credentials` and `migrate_credential` may append
+	pub fn is_lineage(&self) -> bool {
+		matches!(self, Self::Migrated | Self::Split | Self::Merged)
+	}
+
+	/// Whether `next` may follow this status in a verifiable credential trail
+	pub fn can_transition_to(&self, next: &Self) -> bool {
+		match (self, next) {
+			(Self::Pending, Self::Created) => true,
+			(Self::Suspended, Self::Resumed | Self::Frozen) => true,
+			(
+				Self::Created | Self::Scanned | Self::Resumed | Self::Custom(_),
+				Self::Scanned |
+				Self::Suspended |
+				Self::Frozen |
+				Self::Migrated |
+				Self::Split |
+				Self::Merged |
+				Self::Custom(_),
+			) => true,
+			_ => false,
+		}
+	}
 }
 
 #[derive(
 	Encode, Decode, CloneNoBound, PartialEqNoBound, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen,
 )]
-#[codec(mel_bound(AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
-pub struct VerifiableCredentialLog<AccountId, BlockNumber>
+#[scale_info(skip_type_params(MetadataSize))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen, Hash: MaxEncodedLen))]
+pub struct VerifiableCredentialLog<AccountId, BlockNumber, Hash, MetadataSize>
 where
 	AccountId: Clone + PartialEq + Debug,
 	BlockNumber: Clone + PartialEq + Debug + PartialOrd,
+	Hash: Clone + PartialEq + Debug,
+	MetadataSize: Get<u32>,
 {
 	pub account_id: Option<AccountId>,
 
 	pub status: VerifiableCredentialStatus,
 
 	pub block_number: Option<BlockNumber>,
+
+	// Time in milliseconds since the Unix epoch
+	pub timestamp: Option<u64>,
+
+	// Location code, sensor reading or other metadata attached by the tracer
+	pub metadata: Option<BoundedVec<u8, MetadataSize>>,
+
+	// Hash of the evidence backing the log entry
+	pub evidence_hash: Option<Hash>,
+
+	// Issuer role id held by the tracer
+	pub role: Option<u16>,
 }
 
-impl<AccountId, BlockNumber> VerifiableCredentialLog<AccountId, BlockNumber>
+impl<AccountId, BlockNumber, Hash, MetadataSize>
+	VerifiableCredentialLog<AccountId, BlockNumber, Hash, MetadataSize>
 where
 	AccountId: Clone + PartialEq + Debug,
 	BlockNumber: Clone + PartialEq + Debug + PartialOrd,
+	Hash: Clone + PartialEq + Debug,
+	MetadataSize: Get<u32>,
 {
 	pub fn default() -> Self {
-		Self { account_id: None, status: VerifiableCredentialStatus::Pending, block_number: None }
+		Self {
+			account_id: None,
+			status: VerifiableCredentialStatus::Pending,
+			block_number: None,
+			timestamp: None,
+			metadata: None,
+			evidence_hash: None,
+			role: None,
+		}
+	}
+}
+
+/// Balance reserved for a stored item and the account it is refunded to
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct StorageDeposit<AccountId, Balance> {
+	// Account the deposit is reserved from
+	pub depositor: AccountId,
+
+	// Reserved amount
+	pub amount: Balance,
+}
+
+/// Merkle root anchored for a batch of verifiable credential fingerprints
+#[derive(
+	Encode, Decode, CloneNoBound, PartialEqNoBound, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen,
+)]
+#[codec(mel_bound(AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen, Hash: MaxEncodedLen))]
+pub struct CredentialBatchMetadata<AccountId, BlockNumber, Hash>
+where
+	AccountId: Clone + PartialEq + Debug,
+	BlockNumber: Clone + PartialEq + Debug + PartialOrd,
+	Hash: Clone + PartialEq + Debug,
+{
+	// Merkle root of the hashed fingerprints
+	pub merkle_root: Hash,
+
+	// Issuer's AccountId
+	pub issuer: AccountId,
+
+	// Anchored block number
+	pub block_number: BlockNumber,
+
+	// Number of credentials in the batch
+	pub batch_size: u32,
+
+	// Revoked block number of the whole batch
+	pub revoked_block_number: Option<BlockNumber>,
+}
+
+/// Status of a verifiable credential anchored in a batch
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum BatchCredentialStatus {
+	Valid,
+	Revoked,
+	InvalidProof,
+}
+
+/// Scanning device registered by a DID to sign trail events on its behalf
+#[derive(
+	Encode, Decode, CloneNoBound, PartialEqNoBound, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(DIDURISize, LabelSize, MaxStatuses))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+pub struct Device<AccountId, BlockNumber, DIDURISize, LabelSize, MaxStatuses>
+where
+	AccountId: Clone + PartialEq + Debug,
+	BlockNumber: Clone + PartialEq + Debug + PartialOrd,
+	DIDURISize: Get<u32>,
+	LabelSize: Get<u32>,
+	MaxStatuses: Get<u32>,
+{
+	// DID URI the device is registered by
+	pub did_uri: BoundedVec<u8, DIDURISize>,
+
+	// DID controller's AccountId
+	pub account_id: AccountId,
+
+	// Human readable label of the device
+	pub label: BoundedVec<u8, LabelSize>,
+
+	// Statuses the device may append to a trail
+	pub allowed_statuses: BoundedVec<VerifiableCredentialStatus, MaxStatuses>,
+
+	// Registered block number
+	pub block_number: BlockNumber,
+}
+
+/// Role defined by an issuer, such as manufacturer, carrier, customs or retailer
+#[derive(
+	Encode, Decode, CloneNoBound, PartialEqNoBound, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(NameSize, MaxStatuses))]
+#[codec(mel_bound(BlockNumber: MaxEncodedLen))]
+pub struct Role<BlockNumber, NameSize, MaxStatuses>
+where
+	BlockNumber: Clone + PartialEq + Debug + PartialOrd,
+	NameSize: Get<u32>,
+	MaxStatuses: Get<u32>,
+{
+	// Human readable name of the role
+	pub name: BoundedVec<u8, NameSize>,
+
+	// Statuses members of the role may append to the trails of the issuer
+	pub allowed_statuses: BoundedVec<VerifiableCredentialStatus, MaxStatuses>,
+
+	// Number of accounts and DIDs assigned to the role
+	pub members: u32,
+
+	// Registered block number
+	pub block_number: BlockNumber,
+}
+
+/// Account or DID assigned to an issuer role
+#[derive(
+	Encode, Decode, CloneNoBound, PartialEqNoBound, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(DIDURISize))]
+#[codec(mel_bound(AccountId: MaxEncodedLen))]
+pub enum RoleMember<AccountId, DIDURISize>
+where
+	AccountId: Clone + PartialEq + Debug,
+	DIDURISize: Get<u32>,
+{
+	// Signing account of `trace_credential`
+	Account(AccountId),
+	// DID of the devices signing `trace_credential_signed`
+	DID(BoundedVec<u8, DIDURISize>),
+}
+
+/// Trail event signed by a registered device
+#[derive(
+	Encode, Decode, CloneNoBound, PartialEqNoBound, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(VCFingerPrintSize, MetadataSize))]
+#[codec(mel_bound(Hash: MaxEncodedLen))]
+pub struct DeviceTracePayload<Hash, VCFingerPrintSize, MetadataSize>
+where
+	Hash: Clone + PartialEq + Debug,
+	VCFingerPrintSize: Get<u32>,
+	MetadataSize: Get<u32>,
+{
+	// Device public key
+	pub device_key: DevicePublicKey,
+
+	// Genesis hash of the chain the payload is signed for
+	pub genesis_hash: Hash,
+
+	// Nonce of the device, incremented on every trail event
+	pub nonce: u64,
+
+	// VC fingerprint
+	pub vc_fingerprint: BoundedVec<u8, VCFingerPrintSize>,
+
+	// Verifiable credential status
+	pub status: VerifiableCredentialStatus,
+
+	// Location code, sensor reading or other metadata attached by the device
+	pub metadata: Option<BoundedVec<u8, MetadataSize>>,
+
+	// Hash of the evidence backing the log entry
+	pub evidence_hash: Option<Hash>,
+}
+
+/// Merkle commitment of trail entries pruned from state
+#[derive(
+	Encode, Decode, CloneNoBound, PartialEqNoBound, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen,
+)]
+#[codec(mel_bound(BlockNumber: MaxEncodedLen, Hash: MaxEncodedLen))]
+pub struct ArchivedTrailSegment<BlockNumber, Hash>
+where
+	BlockNumber: Clone + PartialEq + Debug + PartialOrd,
+	Hash: Clone + PartialEq + Debug,
+{
+	// Merkle root of the pruned entries, each leaf hashing the sequence number and the entry
+	pub merkle_root: Hash,
+
+	// Sequence number of the first pruned entry
+	pub start: u32,
+
+	// Number of pruned entries
+	pub count: u32,
+
+	// Archived block number
+	pub archived_block_number: BlockNumber,
+}
+
+/// Filter of a verifiable credential trail query. Unset fields match every entry.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct TrailFilter<AccountId, BlockNumber> {
+	// Status of the entry
+	pub status: Option<VerifiableCredentialStatus>,
+
+	// AccountId the entry was logged for
+	pub account_id: Option<AccountId>,
+
+	// First block number of the range the entry was logged in
+	pub from_block: Option<BlockNumber>,
+
+	// Last block number of the range the entry was logged in
+	pub to_block: Option<BlockNumber>,
+}
+
+impl<AccountId, BlockNumber> TrailFilter<AccountId, BlockNumber>
+where
+	AccountId: Clone + PartialEq + Debug,
+	BlockNumber: Clone + PartialEq + Debug + PartialOrd,
+{
+	/// Whether a trail entry matches every field set in the filter
+	pub fn matches<Hash, MetadataSize>(
+		&self,
+		vc_log: &VerifiableCredentialLog<AccountId, BlockNumber, Hash, MetadataSize>,
+	) -> bool
+	where
+		Hash: Clone + PartialEq + Debug,
+		MetadataSize: Get<u32>,
+	{
+		if self.status.as_ref().map_or(false, |status| *status != vc_log.status) {
+			return false
+		}
+		if self.account_id.is_some() && vc_log.account_id != self.account_id {
+			return false
+		}
+		match &vc_log.block_number {
+			| Some(logged) =>
+				self.from_block.as_ref().map_or(true, |from| logged >= from) &&
+					self.to_block.as_ref().map_or(true, |to| logged <= to),
+			| None => self.from_block.is_none() && self.to_block.is_none(),
+		}
+	}
+}
+
+/// Current status summary of a verifiable credential trail
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct TrailSummary<BlockNumber> {
+	// Status of the latest entry
+	pub current_status: Option<VerifiableCredentialStatus>,
+
+	// Whether the verifiable credential is active
+	pub active: Option<bool>,
+
+	// Number of entries ever logged
+	pub trail_length: u32,
+
+	// Number of entries archived out of state
+	pub archived_entries: u32,
+
+	// Block number of the latest entry
+	pub last_block_number: Option<BlockNumber>,
+}
+
+/// Page of a verifiable credential trail query
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, Eq, RuntimeDebugNoBound, TypeInfo)]
+#[scale_info(skip_type_params(MetadataSize))]
+pub struct TrailPage<AccountId, BlockNumber, Hash, MetadataSize>
+where
+	AccountId: Clone + PartialEq + Debug,
+	BlockNumber: Clone + PartialEq + Debug + PartialOrd,
+	Hash: Clone + PartialEq + Debug,
+	MetadataSize: Get<u32>,
+{
+	// Matching entries with their sequence numbers
+	pub entries: Vec<(u32, VerifiableCredentialLog<AccountId, BlockNumber, Hash, MetadataSize>)>,
+
+	// Sequence number to resume the query from, `None` once the trail is exhausted
+	pub next_cursor: Option<u32>,
+
+	// Current status summary
+	pub summary: TrailSummary<BlockNumber>,
+}
+
+/// Verifiable credential resolution output
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct VerifiableCredentialResolution<AccountId, BlockNumber> {
+	pub vc_fingerprint: Vec<u8>,
+
+	// Controller's AccountId
+	pub account_id: Option<AccountId>,
+
+	// Holder's public key
+	pub public_key: Vec<u8>,
+
+	// Issuer's AccountId
+	pub issuer: AccountId,
+
+	// Issuer's DID URI
+	pub issuer_did: Option<Vec<u8>>,
+
+	// Subject's DID URI
+	pub subject_did: Option<Vec<u8>>,
+
+	pub active: Option<bool>,
+
+	pub block_number: BlockNumber,
+
+	pub updated_block_number: BlockNumber,
+
+	// Block number from which the credential is expired
+	pub expiry_block_number: Option<BlockNumber>,
+
+	// ISO 8601 created time
+	pub created: Vec<u8>,
+
+	// ISO 8601 updated time
+	pub updated: Vec<u8>,
+}
+
+/// Validity of a verifiable credential at a block
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum CredentialValidity {
+	Valid,
+	Inactive,
+	Expired,
+	Revoked,
+	Deleted,
+}
+
+/// Status of a verifiable credential at a block
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct CredentialStatusReport<AccountId, BlockNumber> {
+	pub validity: CredentialValidity,
+
+	// Status of the latest trail entry
+	pub current_status: Option<VerifiableCredentialStatus>,
+
+	// Issuer's AccountId, unknown once the credential is deleted
+	pub issuer: Option<AccountId>,
+
+	// Block number from which the credential is expired
+	pub expiry_block_number: Option<BlockNumber>,
+
+	// Block number the credential was revoked at
+	pub revoked_block_number: Option<BlockNumber>,
+
+	// Block number the credential was deleted at
+	pub deleted_block_number: Option<BlockNumber>,
+}
+
+/// Hash function computing verifiable credential fingerprints. Fingerprints are multihashes:
+/// the unsigned varint multihash code of the function, the unsigned varint digest length and
+/// the digest.
+pub trait FingerprintHasher {
+	/// Multihash code of the hash function
+	const CODE: u64;
+
+	/// Digest length in bytes
+	const LENGTH: usize;
+
+	/// Digest of `data`, `LENGTH` bytes long
+	fn digest(data: &[u8]) -> Vec<u8>;
+
+	/// Multihash prefix of the fingerprints computed by the hash function
+	fn prefix() -> Vec<u8> {
+		let mut prefix = Vec::new();
+		for mut value in [Self::CODE, Self::LENGTH as u64] {
+			while value >= 0x80 {
+				prefix.push((value as u8 & 0x7f) | 0x80);
+				value >>= 7;
+			}
+			prefix.push(value as u8);
+		}
+		prefix
+	}
+
+	/// Multihash fingerprint of `data`
+	fn fingerprint(data: &[u8]) -> Vec<u8> {
+		let mut fingerprint = Self::prefix();
+		fingerprint.extend(Self::digest(data));
+		fingerprint
+	}
+
+	/// Whether `fingerprint` is a well-formed multihash of the hash function
+	fn is_fingerprint(fingerprint: &[u8]) -> bool {
+		let prefix = Self::prefix();
+		fingerprint.len() == prefix.len() + Self::LENGTH && fingerprint.starts_with(&prefix)
+	}
+}
+
+/// BLAKE2b-256 fingerprints, multihash code `0xb220`
+pub struct Blake2b256;
+
+impl FingerprintHasher for Blake2b256 {
+	const CODE: u64 = 0xb220;
+	const LENGTH: usize = 32;
+
+	fn digest(data: &[u8]) -> Vec<u8> {
+		sp_io::hashing::blake2_256(data).to_vec()
+	}
+}
+
+/// SHA2-256 fingerprints, multihash code `0x12`
+pub struct Sha2256;
+
+impl FingerprintHasher for Sha2256 {
+	const CODE: u64 = 0x12;
+	const LENGTH: usize = 32;
+
+	fn digest(data: &[u8]) -> Vec<u8> {
+		sp_io::hashing::sha2_256(data).to_vec()
+	}
+}
+
+/// Keccak-256 fingerprints, multihash code `0x1b`
+pub struct Keccak256;
+
+impl FingerprintHasher for Keccak256 {
+	const CODE: u64 = 0x1b;
+	const LENGTH: usize = 32;
+
+	fn digest(data: &[u8]) -> Vec<u8> {
+		sp_io::hashing::keccak_256(data).to_vec()
+	}
+}
+
+/// DID created at genesis
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct GenesisDID<AccountId> {
+	// DID URI
+	#[cfg_attr(feature = "std", serde(with = "text"))]
+	pub did_uri: Vec<u8>,
+
+	// Controller of the DID
+	pub controller: AccountId,
+
+	// DID Document metadata
+	#[cfg_attr(feature = "std", serde(with = "text"))]
+	pub did_document_metadata: Vec<u8>,
+}
+
+/// Verifiable credential anchored at genesis
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct GenesisCredential<AccountId> {
+	// VC fingerprint as hex
+	#[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))]
+	pub vc_fingerprint: Vec<u8>,
+
+	// Issuer of the verifiable credential
+	pub issuer: AccountId,
+
+	// Holder public key as hex
+	#[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))]
+	pub public_key: Vec<u8>,
+
+	// DID URI of the issuer
+	#[cfg_attr(feature = "std", serde(with = "optional_text"))]
+	pub issuer_did: Option<Vec<u8>>,
+
+	// DID URI of the subject
+	#[cfg_attr(feature = "std", serde(with = "optional_text"))]
+	pub subject_did: Option<Vec<u8>>,
+}
+
+/// Issuer role registered at genesis
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct GenesisRole<AccountId> {
+	// Issuer defining the role
+	pub issuer: AccountId,
+
+	// Role id
+	pub role_id: u16,
+
+	// Role name
+	#[cfg_attr(feature = "std", serde(with = "text"))]
+	pub name: Vec<u8>,
+
+	// Statuses role holders may append
+	pub allowed_statuses: Vec<VerifiableCredentialStatus>,
+
+	// Accounts holding the role
+	pub members: Vec<AccountId>,
+}
+
+/// Custom status registered in the vocabulary of an issuer at genesis
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct GenesisCustomStatus<AccountId> {
+	// Issuer registering the status
+	pub issuer: AccountId,
+
+	// Custom status id
+	pub status_id: u16,
+
+	// Status label
+	#[cfg_attr(feature = "std", serde(with = "text"))]
+	pub label: Vec<u8>,
+}
+
+/// Byte strings written as UTF-8 text in chain specs
+#[cfg(feature = "std")]
+mod text {
+	use serde::{Deserialize, Deserializer, Serializer};
+
+	pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(&String::from_utf8_lossy(bytes))
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+		String::deserialize(deserializer).map(String::into_bytes)
+	}
+}
+
+/// Optional byte strings written as UTF-8 text in chain specs
+#[cfg(feature = "std")]
+mod optional_text {
+	use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+	pub fn serialize<S: Serializer>(
+		bytes: &Option<Vec<u8>>,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		bytes.as_deref().map(String::from_utf8_lossy).serialize(serializer)
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(
+		deserializer: D,
+	) -> Result<Option<Vec<u8>>, D::Error> {
+		Option::<String>::deserialize(deserializer).map(|text| text.map(String::into_bytes))
 	}
 }
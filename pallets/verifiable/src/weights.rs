@@ -8,8 +8,26 @@ pub trait WeightInfo {
 	fn revoke_verifiable_credential() -> Weight;
 	fn create_verifiable_credential() -> Weight;
 	fn update_verifiable_credential() -> Weight;
-	fn delete_verifiable_credential() -> Weight;
+	fn delete_verifiable_credential(n: u32) -> Weight;
 	fn verify_verifiable_credential() -> Weight;
+	fn anchor_credential_batch() -> Weight;
+	fn revoke_credential_batch() -> Weight;
+	fn revoke_batch_credential() -> Weight;
+	fn split_credential(n: u32) -> Weight;
+	fn merge_credentials(n: u32) -> Weight;
+	fn migrate_credential() -> Weight;
+	fn register_device() -> Weight;
+	fn remove_device() -> Weight;
+	fn trace_credential_signed() -> Weight;
+	fn archive_verifiable_credential_trail(n: u32) -> Weight;
+	fn register_custom_status() -> Weight;
+	fn remove_custom_status() -> Weight;
+	fn register_role() -> Weight;
+	fn remove_role() -> Weight;
+	fn assign_role() -> Weight;
+	fn unassign_role() -> Weight;
+	fn set_rate_limit_exemption() -> Weight;
+	fn anchor_credential_content(n: u32) -> Weight;
 }
 
 impl WeightInfo for () {
@@ -41,11 +59,83 @@ impl WeightInfo for () {
 		Weight::from_ref_time(10_000_000_u64)
 	}
 
-	fn delete_verifiable_credential() -> Weight {
-		Weight::from_ref_time(10_000_000_u64)
+	fn delete_verifiable_credential(n: u32) -> Weight {
+		Weight::from_ref_time(10_000_000_u64.saturating_mul(n as u64 + 1))
 	}
 
 	fn verify_verifiable_credential() -> Weight {
 		Weight::from_ref_time(10_000_000_u64)
 	}
+
+	fn anchor_credential_batch() -> Weight {
+		Weight::from_ref_time(10_000_000_u64)
+	}
+
+	fn revoke_credential_batch() -> Weight {
+		Weight::from_ref_time(10_000_000_u64)
+	}
+
+	fn revoke_batch_credential() -> Weight {
+		Weight::from_ref_time(10_000_000_u64)
+	}
+
+	fn split_credential(n: u32) -> Weight {
+		Weight::from_ref_time(10_000_000_u64.saturating_mul(n as u64 + 1))
+	}
+
+	fn merge_credentials(n: u32) -> Weight {
+		Weight::from_ref_time(10_000_000_u64.saturating_mul(n as u64 + 1))
+	}
+
+	fn migrate_credential() -> Weight {
+		Weight::from_ref_time(10_000_000_u64)
+	}
+
+	fn register_device() -> Weight {
+		Weight::from_ref_time(10_000_000_u64)
+	}
+
+	fn remove_device() -> Weight {
+		Weight::from_ref_time(10_000_000_u64)
+	}
+
+	fn trace_credential_signed() -> Weight {
+		Weight::from_ref_time(10_000_000_u64)
+	}
+
+	fn archive_verifiable_credential_trail(n: u32) -> Weight {
+		Weight::from_ref_time(10_000_000_u64.saturating_mul(n as u64 + 1))
+	}
+
+	fn register_custom_status() -> Weight {
+		Weight::from_ref_time(10_000_000_u64)
+	}
+
+	fn remove_custom_status() -> Weight {
+		Weight::from_ref_time(10_000_000_u64)
+	}
+
+	fn register_role() -> Weight {
+		Weight::from_ref_time(10_000_000_u64)
+	}
+
+	fn remove_role() -> Weight {
+		Weight::from_ref_time(10_000_000_u64)
+	}
+
+	fn assign_role() -> Weight {
+		Weight::from_ref_time(10_000_000_u64)
+	}
+
+	fn unassign_role() -> Weight {
+		Weight::from_ref_time(10_000_000_u64)
+	}
+
+	fn set_rate_limit_exemption() -> Weight {
+		Weight::from_ref_time(10_000_000_u64)
+	}
+
+	fn anchor_credential_content(n: u32) -> Weight {
+		Weight::from_ref_time(10_000_000_u64.saturating_add(1_000_u64.saturating_mul(n as u64)))
+	}
 }
@@ -6,6 +6,7 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+use frame_support::PalletId;
 use pallet_grandpa::{
 	fg_primitives, AuthorityId as GrandpaId, AuthorityList as GrandpaAuthorityList,
 };
@@ -15,10 +16,14 @@ use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify,
+		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, DispatchInfoOf,
+		IdentifyAccount, NumberFor, One, PostDispatchInfoOf, SignedExtension, Verify,
 	},
-	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, MultiSignature,
+	transaction_validity::{
+		TransactionPriority, TransactionSource, TransactionValidity, TransactionValidityError,
+		ValidTransaction,
+	},
+	ApplyExtrinsicResult, DispatchResult, MultiSignature, RuntimeDebug,
 };
 use sp_std::prelude::*;
 #[cfg(feature = "std")]
@@ -41,6 +46,7 @@ pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier};
+pub use pallet_verifiable::Call as VerifiableCall;
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
 pub use sp_runtime::{Perbill, Permill};
@@ -273,8 +279,35 @@ parameter_types! {
 	pub const MaxMetadataSize: u32 = 2048;
 	pub const MaxDIDsPerAccount: u32 = 100;
 	pub const PublicKeySize: u32 = 128;
-	pub const VCFingerPrintSize: u32 = 32;
+	pub const VCFingerPrintSize: u32 = 64;
+	pub const MaxCredentialContentSize: u32 = 64 * 1024;
 	pub const DIDURISize: u32 = 57;
+	pub const MaxLineageSize: u32 = 100;
+	pub const TrailMetadataSize: u32 = 256;
+	pub const DepositBase: Balance = 100 * EXISTENTIAL_DEPOSIT;
+	pub const DepositPerByte: Balance = EXISTENTIAL_DEPOSIT / 5;
+	pub const CustomStatusLabelSize: u32 = 64;
+	pub const MaxCustomStatuses: u32 = 64;
+	pub const RoleNameSize: u32 = 32;
+	pub const MaxRoles: u32 = 64;
+	/// Every built-in status plus every custom status an issuer may register
+	pub MaxRoleStatuses: u32 = 9 + MaxCustomStatuses::get();
+	pub const TrailArchiveAge: BlockNumber = 30 * DAYS;
+	pub const MaxArchiveEntries: u32 = 256;
+	pub const DeviceLabelSize: u32 = 32;
+	/// Every built-in status plus every custom status an issuer may register
+	pub MaxDeviceStatuses: u32 = 9 + MaxCustomStatuses::get();
+	pub const DeviceTracePeriod: BlockNumber = 100;
+	pub const MaxDeviceTracesPerPeriod: u32 = 600;
+	/// Low priority, so free device traces cannot crowd out paid transactions
+	pub const DeviceTracePriority: TransactionPriority = 100;
+	pub const FeelessTraceEra: BlockNumber = DAYS;
+	pub const MaxFeelessTracesPerEra: u32 = 500;
+	/// Feeless traces rank below every paid transaction
+	pub const FeelessTracePriority: TransactionPriority = 0;
+	pub const RateLimitWindow: BlockNumber = 10 * MINUTES;
+	pub const MaxOperationsPerWindow: u32 = 100;
+	pub const MaxIssuerTracesPerWindow: u32 = 10_000;
 }
 
 impl pallet_verifiable::Config for Runtime {
@@ -284,7 +317,33 @@ impl pallet_verifiable::Config for Runtime {
 	type MaxDIDsPerAccount = MaxDIDsPerAccount;
 	type PublicKeySize = PublicKeySize;
 	type VCFingerPrintSize = VCFingerPrintSize;
+	type FingerprintHasher = pallet_verifiable::Blake2b256;
+	type MaxCredentialContentSize = MaxCredentialContentSize;
 	type DIDURISize = DIDURISize;
+	type MaxLineageSize = MaxLineageSize;
+	type TrailMetadataSize = TrailMetadataSize;
+	type TimeProvider = Timestamp;
+	type Currency = Balances;
+	type DepositBase = DepositBase;
+	type DepositPerByte = DepositPerByte;
+	type CustomStatusLabelSize = CustomStatusLabelSize;
+	type MaxCustomStatuses = MaxCustomStatuses;
+	type RoleNameSize = RoleNameSize;
+	type MaxRoles = MaxRoles;
+	type MaxRoleStatuses = MaxRoleStatuses;
+	type TrailArchiveAge = TrailArchiveAge;
+	type MaxArchiveEntries = MaxArchiveEntries;
+	type DeviceLabelSize = DeviceLabelSize;
+	type MaxDeviceStatuses = MaxDeviceStatuses;
+	type DeviceTracePeriod = DeviceTracePeriod;
+	type MaxDeviceTracesPerPeriod = MaxDeviceTracesPerPeriod;
+	type DeviceTracePriority = DeviceTracePriority;
+	type FeelessTraceEra = FeelessTraceEra;
+	type MaxFeelessTracesPerEra = MaxFeelessTracesPerEra;
+	type RateLimitWindow = RateLimitWindow;
+	type MaxOperationsPerWindow = MaxOperationsPerWindow;
+	type MaxIssuerTracesPerWindow = MaxIssuerTracesPerWindow;
+	type RateLimitOrigin = frame_system::EnsureRoot<AccountId>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -323,9 +382,117 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
+	ChargeTransactionPaymentOrFeelessTrace,
+);
+
+/// Charges transaction fees like `ChargeTransactionPayment`, except for `trace_credential` calls
+/// signed by accounts holding a role of the credential issuer that allows the traced status.
+/// Those are free up to `MaxFeelessTracesPerEra` calls per account and era, after which fees
+/// are charged again, and their trail deposits are reserved from the credential issuer. It encodes
+/// like `ChargeTransactionPayment` and keeps its identifier, so wallets sign transactions as
+/// before.
+#[derive(
+	codec::Encode, codec::Decode, Clone, Eq, PartialEq, RuntimeDebug, scale_info::TypeInfo,
+)]
+pub struct ChargeTransactionPaymentOrFeelessTrace(
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 );
 
+impl ChargeTransactionPaymentOrFeelessTrace {
+	fn is_feeless_trace(who: &AccountId, call: &RuntimeCall) -> bool {
+		match call {
+			| RuntimeCall::Verifiable(pallet_verifiable::Call::trace_credential {
+				vc_fingerprint,
+				status,
+				..
+			}) => Verifiable::is_feeless_trace(who, vc_fingerprint, status),
+			| _ => false,
+		}
+	}
+}
+
+impl From<Balance> for ChargeTransactionPaymentOrFeelessTrace {
+	fn from(tip: Balance) -> Self {
+		Self(pallet_transaction_payment::ChargeTransactionPayment::from(tip))
+	}
+}
+
+impl SignedExtension for ChargeTransactionPaymentOrFeelessTrace {
+	const IDENTIFIER: &'static str = "ChargeTransactionPayment";
+	type AccountId = AccountId;
+	type Call = RuntimeCall;
+	type AdditionalSigned = ();
+	// `None` for feeless trail events
+	type Pre = Option<
+		<pallet_transaction_payment::ChargeTransactionPayment<Runtime> as SignedExtension>::Pre,
+	>;
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		if Self::is_feeless_trace(who, call) {
+			// Feeless traces of an account taking the same quota slot replace each other in the
+			// pool, so it never holds more feeless traces than the quota admits. They expire
+			// with the quota era.
+			let (era, count) = Verifiable::feeless_trace_slot(who);
+			let blocks_left =
+				FeelessTraceEra::get() - System::block_number() % FeelessTraceEra::get();
+			return ValidTransaction::with_tag_prefix(Self::IDENTIFIER)
+				.priority(FeelessTracePriority::get())
+				.and_provides((who, era, count))
+				.longevity(blocks_left.into())
+				.build()
+		}
+		self.0.validate(who, call, info, len)
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		if Self::is_feeless_trace(who, call) {
+			Verifiable::note_feeless_trace(who);
+			return Ok(None)
+		}
+		self.0.pre_dispatch(who, call, info, len).map(Some)
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		match pre {
+			| Some(Some(pre)) =>
+				pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::post_dispatch(
+					Some(pre),
+					info,
+					post_info,
+					len,
+					result,
+				),
+			| Some(None) => {
+				Verifiable::end_feeless_trace();
+				Ok(())
+			},
+			| None => Ok(()),
+		}
+	}
+}
+
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
@@ -338,8 +505,20 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+parameter_types! {
+	/// Issuer of the verifiable credentials migrated from storage version 1: the sudo key, or a
+	/// keyless account if there is none
+	pub LegacyCredentialIssuer: AccountId = Sudo::key()
+		.unwrap_or_else(|| PalletId(*b"vrf/lgcy").into_account_truncating());
+}
+
+/// Storage migrations run on runtime upgrade.
+pub type Migrations =
+	(pallet_verifiable::migrations::v2::MigrateToV2<Runtime, LegacyCredentialIssuer>,);
+
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
 extern crate frame_benchmarking;
@@ -503,6 +682,126 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_verifiable_runtime_api::VerifiableApi<Block, Hash, AccountId, BlockNumber, TrailMetadataSize>
+		for Runtime
+	{
+		fn resolve_did(
+			did_uri: Vec<u8>,
+		) -> Option<pallet_verifiable::DIDResolution<AccountId, BlockNumber>> {
+			Verifiable::resolve_did(&did_uri)
+		}
+
+		fn resolve_verifiable_credential(
+			vc_fingerprint: Vec<u8>,
+		) -> Option<pallet_verifiable::VerifiableCredentialResolution<AccountId, BlockNumber>> {
+			Verifiable::resolve_verifiable_credential(&vc_fingerprint)
+		}
+
+		fn credential_status(
+			vc_fingerprint: Vec<u8>,
+		) -> Option<pallet_verifiable::CredentialStatusReport<AccountId, BlockNumber>> {
+			Verifiable::credential_status(&vc_fingerprint)
+		}
+
+		fn dids_by_account(
+			account_id: AccountId,
+			start_after: Option<Vec<u8>>,
+			limit: u32,
+		) -> Vec<Vec<u8>> {
+			Verifiable::dids_by_account(&account_id, start_after, limit)
+		}
+
+		fn did_storage_keys(did_uri: Vec<u8>) -> Vec<Vec<u8>> {
+			Verifiable::did_storage_keys(&did_uri)
+		}
+
+		fn credential_storage_keys(vc_fingerprint: Vec<u8>) -> Vec<Vec<u8>> {
+			Verifiable::credential_storage_keys(&vc_fingerprint)
+		}
+
+		fn verify_batch_credential(
+			batch_id: u64,
+			vc_fingerprint: Vec<u8>,
+			proof: Vec<Hash>,
+		) -> Option<pallet_verifiable::BatchCredentialStatus> {
+			Verifiable::verify_batch_credential(batch_id, &vc_fingerprint, &proof)
+		}
+
+		fn credentials_by_issuer_did(
+			did_uri: Vec<u8>,
+			start_after: Option<Vec<u8>>,
+			limit: u32,
+		) -> Vec<Vec<u8>> {
+			Verifiable::credentials_by_issuer_did(&did_uri, start_after, limit)
+		}
+
+		fn credentials_by_subject_did(
+			did_uri: Vec<u8>,
+			start_after: Option<Vec<u8>>,
+			limit: u32,
+		) -> Vec<Vec<u8>> {
+			Verifiable::credentials_by_subject_did(&did_uri, start_after, limit)
+		}
+
+		fn credentials_by_holder(
+			public_key: Vec<u8>,
+			start_after: Option<Vec<u8>>,
+			limit: u32,
+		) -> Vec<Vec<u8>> {
+			Verifiable::credentials_by_holder(&public_key, start_after, limit)
+		}
+
+		fn get_verifiable_credential_trail(
+			vc_fingerprint: Vec<u8>,
+			start: u32,
+			limit: u32,
+		) -> Vec<
+			pallet_verifiable::VerifiableCredentialLog<AccountId, BlockNumber, Hash, TrailMetadataSize>,
+		> {
+			Verifiable::verifiable_credential_trail(&vc_fingerprint, start, limit)
+		}
+
+		fn custom_status_vocabulary(issuer: AccountId) -> Vec<(u16, Vec<u8>)> {
+			Verifiable::custom_status_vocabulary(&issuer)
+		}
+
+		fn query_trail(
+			vc_fingerprint: Vec<u8>,
+			filter: pallet_verifiable::TrailFilter<AccountId, BlockNumber>,
+			cursor: Option<u32>,
+			limit: u32,
+		) -> Option<pallet_verifiable::TrailPage<AccountId, BlockNumber, Hash, TrailMetadataSize>> {
+			Verifiable::query_trail(&vc_fingerprint, filter, cursor, limit)
+		}
+
+		fn verify_archived_trail_log(
+			vc_fingerprint: Vec<u8>,
+			segment: u32,
+			sequence: u32,
+			vc_log: pallet_verifiable::VerifiableCredentialLog<
+				AccountId,
+				BlockNumber,
+				Hash,
+				TrailMetadataSize,
+			>,
+			proof: Vec<Hash>,
+		) -> bool {
+			Verifiable::verify_archived_trail_log(&vc_fingerprint, segment, sequence, &vc_log, &proof)
+		}
+
+		fn credential_ancestors(vc_fingerprint: Vec<u8>, limit: u32) -> Vec<Vec<u8>> {
+			Verifiable::credential_ancestors(&vc_fingerprint, limit)
+		}
+
+		fn credential_descendants(vc_fingerprint: Vec<u8>, limit: u32) -> Vec<Vec<u8>> {
+			Verifiable::credential_descendants(&vc_fingerprint, limit)
+		}
+
+		fn credential_migration_chain(vc_fingerprint: Vec<u8>, limit: u32) -> Vec<Vec<u8>> {
+			Verifiable::credential_migration_chain(&vc_fingerprint, limit)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (